@@ -0,0 +1,44 @@
+//! heapless 0.8 only provides `pool` on 32-bit x86, and on ARM targets passing its build script's `clrex` probe (`cfg(arm_llsc)`).
+//! That probe isn't visible to dependents, so it's mirrored here as `cfg(valrow_heapless_pool)`.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if rustc_minor().is_some_and(|minor| minor >= 80) { println!("cargo:rustc-check-cfg=cfg(valrow_heapless_pool)") } // older cargos warn about this
+    if env::var_os("CARGO_FEATURE_HEAPLESS").is_none() { return }
+
+    let pool = match env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default().as_str() {
+        "x86"   => true,
+        "arm"   => arm_llsc_probe(), // heapless skips `aarch64`, which has `clrex` but no `ldrex`/`strex`: that's a separate `target_arch`
+        _       => false,
+    };
+    if pool { println!("cargo:rustc-cfg=valrow_heapless_pool") }
+}
+
+/// `71` for `rustc 1.71.0 (8ede3aae2 2023-07-12)`
+fn rustc_minor() -> Option<u32> {
+    let version = Command::new(env::var_os("RUSTC")?).arg("--version").output().ok()?.stdout;
+    String::from_utf8(version).ok()?.split('.').nth(1)?.parse().ok()
+}
+
+/// Same probe as heapless 0.8's `build.rs`: does `clrex` assemble for this target?
+fn arm_llsc_probe() -> bool {
+    let (Some(rustc), Some(out_dir)) = (env::var_os("RUSTC"), env::var_os("OUT_DIR")) else { return false };
+    let probe = Path::new(&out_dir).join("arm_llsc_probe.rs");
+    if fs::write(&probe, "#![no_std] #[no_mangle] unsafe fn asm() { core::arch::asm!(\"clrex\"); }").is_err() { return false }
+
+    let mut cmd = match env::var_os("RUSTC_WRAPPER") {
+        Some(wrapper) => { let mut cmd = Command::new(wrapper); cmd.arg(rustc); cmd },
+        None => Command::new(rustc),
+    };
+    cmd.stderr(Stdio::null())
+        .args(["--edition=2018", "--crate-name=arm_llsc_probe", "--crate-type=lib", "--out-dir"]).arg(&out_dir)
+        .arg(&probe);
+    if let Ok(target) = env::var("TARGET") { cmd.args(["--target", &target]); }
+    if let Ok(rustflags) = env::var("CARGO_ENCODED_RUSTFLAGS") { cmd.args(rustflags.split('\x1f').filter(|f| !f.is_empty())); }
+    cmd.status().is_ok_and(|status| status.success())
+}
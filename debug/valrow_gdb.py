@@ -0,0 +1,29 @@
+# GDB pretty-printers for valrow, embedded via `#![debugger_visualizer(gdb_script_file = "../debug/valrow_gdb.py")]`.
+# Displays `Valrow<T>` / `ValrowMut<T>` as the borrowed `T`, matching `debug/valrow.natvis`.
+#
+# If gdb refuses to auto-load this, try:  gdb -iex "add-auto-load-safe-path /path/to/your/exe" ...
+
+import gdb
+import re
+
+VALROW_TYPES = re.compile(r"^valrow::(valrow::Valrow|valrow_mut::ValrowMut)<.+>$")
+
+class ValrowPrinter:
+    def __init__(self, val):
+        self.val = val
+
+    def to_string(self):
+        try:
+            t = self.val.type.strip_typedefs().template_argument(0)
+            return self.val.address.cast(t.pointer()).dereference()
+        except (gdb.error, RuntimeError, AttributeError):
+            return self.val["__0"] # no address (e.g. in a register) or no template info
+
+def valrow_lookup(val):
+    t = val.type.strip_typedefs()
+    name = t.tag or t.name
+    if name is not None and VALROW_TYPES.match(name):
+        return ValrowPrinter(val)
+    return None
+
+(gdb.current_objfile() or gdb).pretty_printers.append(valrow_lookup)
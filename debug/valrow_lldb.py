@@ -0,0 +1,43 @@
+# LLDB formatters for valrow.  Displays `Valrow<T>` / `ValrowMut<T>` as the borrowed `T`, matching `debug/valrow.natvis`.
+# rustc can't embed these, so load them manually:
+#
+#   (lldb) command script import /path/to/valrow/debug/valrow_lldb.py
+#
+# Or add the above to your `~/.lldbinit`.
+
+import lldb
+
+VALROW_TYPES = [
+    "^valrow::valrow::Valrow<.+>$",
+    "^valrow::valrow_mut::ValrowMut<.+>$",
+]
+
+def borrowed(valobj):
+    valobj = valobj.GetNonSyntheticValue()
+    t = valobj.GetType().GetTemplateArgumentType(0)
+    if not t.IsValid():
+        return valobj.GetChildAtIndex(0) # no template info
+    return valobj.AddressOf().Cast(t.GetPointerType()).Dereference()
+
+class ValrowSyntheticProvider:
+    def __init__(self, valobj, internal_dict):
+        self.valobj = valobj
+        self.update()
+
+    def update(self):
+        self.target = borrowed(self.valobj)
+
+    def has_children(self):                 return self.target.MightHaveChildren()
+    def num_children(self):                 return self.target.GetNumChildren()
+    def get_child_index(self, name):        return self.target.GetIndexOfChildWithName(name)
+    def get_child_at_index(self, index):    return self.target.GetChildAtIndex(index)
+
+def valrow_summary(valobj, internal_dict):
+    target = borrowed(valobj)
+    return target.GetSummary() or target.GetValue() or ""
+
+def __lldb_init_module(debugger, internal_dict):
+    for regex in VALROW_TYPES:
+        debugger.HandleCommand('type synthetic add -w valrow -l {}.ValrowSyntheticProvider -x "{}"'.format(__name__, regex))
+        debugger.HandleCommand('type summary add -w valrow -F {}.valrow_summary -x "{}"'.format(__name__, regex))
+    debugger.HandleCommand("type category enable valrow")
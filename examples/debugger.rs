@@ -1,4 +1,5 @@
-// For e.g. debugger testing
+// For e.g. debugger testing (see also tests/gdb.rs)
+#![allow(clippy::let_unit_value)] // `d` is deliberately a ZST on the stack
 
 #[cfg(not(feature = "alloc"))] fn main() { panic!("this example requires feature = \"alloc\"") }
 #[cfg(    feature = "alloc" )] fn main() {
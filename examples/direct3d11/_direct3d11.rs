@@ -12,5 +12,9 @@
 //! to implement <code>[valrow]::[Borrowable]</code> and
 //! <code>[PSSetShaderResources](https://microsoft.github.io/windows-docs-rs/doc/windows/Win32/Graphics/Direct3D11/struct.ID3D11DeviceContext.html#method.PSSetShaderResources)</code>
 //! changed to accept <code>&amp;&zwj;\[[Option]&lt;[Valrow]&lt;[ID3D11ShaderResourceView](https://microsoft.github.io/windows-docs-rs/doc/windows/Win32/Graphics/Direct3D11/struct.ID3D11ShaderResourceView.html)&gt;&gt;]</code> instead.
+//!
+//! [`valrow::com`](crate::com) models just enough of COM to do exactly that without Windows:
+//! <code>[com::ComPtr](crate::com::ComPtr)\<I\></code> is [`Borrowable`], and <code>[Valrow]::[as_raw_option_slice](crate::Valrow::as_raw_option_slice)</code>
+//! reinterprets <code>&amp;\[[Option]&lt;[Valrow]&lt;ComPtr&lt;I&gt;&gt;&gt;]</code> as the `I* const*` array `PSSetShaderResources` expects (see `tests/com.rs`.)
 use crate::{self as valrow, *};
 use alloc::vec::Vec;
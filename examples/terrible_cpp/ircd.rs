@@ -18,11 +18,12 @@ fn main() {
 
     // allows use of `channels` within ZST-requiring `users.for_each`
     let mut channels_mut = ValrowMut::new(&mut *channels);
+    let channels_reborrow = ValrowMut::reborrow(&mut channels_mut);
     users.for_each(move |user| {
         let user = user.to_string_lossy();
         print!("PRIVMSG {user} :Check out the following channels!");
 
-        channels_mut.for_each(move |channel| {
+        channels_reborrow.for_each(move |channel| {
             let channel = channel.to_string_lossy();
             print!(" {channel}");
 
@@ -33,6 +34,11 @@ fn main() {
         println!();
     });
 
+    // `channels_reborrow` borrow has ended, `channels_mut` is once more accessible
+    channels_mut.add(cstr!("#valrow"));
+    let channels_mut : &mut Channels = ValrowMut::into_mut(channels_mut);
+    channels_mut.add(cstr!("#ffi"));
+
     // `channels_mut` borrow has ended, `channels` is once more accessible
     channels.for_each(move |channel| print!(" {}", channel.to_string_lossy()));
 }
@@ -49,8 +55,7 @@ mod sealed {
     #[doc = "C++'s global, users"   ] pub struct Users(());
     pub static CHANNELS : Mutex<Channels> = Mutex::new(Channels(()) );
     pub static USERS    : Mutex<Users   > = Mutex::new(Users(())    );
-    unsafe impl valrow::Borrowable for Channels  { type Abi = (); }
-    unsafe impl valrow::Borrowable for Users     { type Abi = (); }
+    valrow::borrowable_zst!(Channels, Users);
 }
 
 impl Channels {
@@ -1,5 +1,7 @@
 #![no_std]
+#![allow(clippy::let_unit_value)] // `let _ = Self::STATIC_CHECK_...;` forces evaluation of static checks
 #![debugger_visualizer(natvis_file = "../debug/valrow.natvis")]
+#![debugger_visualizer(gdb_script_file = "../debug/valrow_gdb.py")]
 #![doc = include_str!("../Readme.md")]
 
 
@@ -19,6 +21,18 @@
 }
 
 #[macro_use] mod macros; #[doc(hidden)] pub use macros::_valrow_macros_prelude;
+#[cfg(feature = "macros")] pub use valrow_macros::vtable;
 mod borrowable;     pub use crate::borrowable::*;
+mod borrowable_mut; pub use crate::borrowable_mut::*;
+mod borrowable_str; pub use crate::borrowable_str::*;
 mod valrow;         pub use crate::valrow::*;
 mod valrow_mut;     pub use crate::valrow_mut::*;
+mod valrow_deref;   pub use crate::valrow_deref::*;
+mod shallow_copy;   pub use crate::shallow_copy::*;
+mod zst_waker;      pub use crate::zst_waker::*;
+#[cfg(feature = "alloc")] mod box_row; #[cfg(feature = "alloc")] pub use crate::box_row::*;
+mod indirect_mut;   pub use crate::indirect_mut::*;
+mod type_lock;      pub use crate::type_lock::*;
+pub mod com;
+pub mod integrations;
+#[cfg(all(feature = "serde", feature = "std"))] mod shared_graph; #[cfg(all(feature = "serde", feature = "std"))] pub use crate::shared_graph::*;
@@ -1,5 +1,6 @@
 use core::ptr::NonNull;
 #[cfg(doc)] use core::alloc::Layout;
+#[cfg(doc)] use crate::TypeLock;
 
 
 
@@ -93,6 +94,7 @@ use core::ptr::NonNull;
 ///
 /// Most realistic uses of such by-address identities are already forbidden by the ban on interior mutability,
 /// but e.g. ZST Mutexes that track lock state by `HashMap<*const ZstMutex, std::sync::Mutex<()>>` could be broken by implementing this trait.
+/// Prefer [`TypeLock`], which tracks lock state in a single `static` per type rather than per address.
 ///
 pub unsafe trait Borrowable
     // : core::maker::Freeze // compiler internal, not exposed by even nightly: https://stdrs.dev/nightly/x86_64-pc-windows-gnu/core/marker/trait.Freeze.html
@@ -116,16 +118,24 @@ pub unsafe trait Borrowable
 // TODO: make a `#[derive(Borrowable)]` that verifies all members are `Borrowable`?
 // TODO: add many more core/alloc/std types to improve the usability of said derive?
 
-#[cfg(xxx)] // ❌: ABI should be more permissive for exclusive borrows
-unsafe impl<'a, T: ?Sized> Borrowable for &'a mut T { type Abi = NonNull<T>;    }
 unsafe impl<'a, T: ?Sized> Borrowable for &'a     T { type Abi = &'a T;         }
 unsafe impl<T: ?Sized> Borrowable for *const T      { type Abi = *const T;      }
 unsafe impl<T: ?Sized> Borrowable for *mut   T      { type Abi = *mut   T;      }
 unsafe impl<T: ?Sized> Borrowable for NonNull<T>    { type Abi = NonNull<T>;    }
+unsafe impl<'a, T: Borrowable          > Borrowable for crate::Valrow   <'a, T> { type Abi = T::Abi;         }
+/// !ZSTs excluded: copies would alias `&mut`.
+/// ```compile_fail
+/// # use valrow::*;
+/// let mut value = 42;
+/// let mut reference = &mut value;
+/// let exclusive = ValrowMut::new(&mut reference);
+/// let shared = Valrow::new(&exclusive); // ❌ won't compile: ValrowMut<&mut i32> isn't a ZST (AbiMut = NonNull<i32>)
+/// ```
+unsafe impl<'a, T: crate::BorrowableMut<AbiMut = ()>> Borrowable for crate::ValrowMut<'a, T> { type Abi = (); }
 
 #[cfg(feature = "alloc")] const _ : () = {
     #[cfg(xxx_borrowable_box)]
-    unsafe impl<T: ?Sized> Borrowable for alloc::boxed ::Box <T> { type Abi = NonNull<T>; } // ❌ UB? See try_to_break_box_valrows below.
+    unsafe impl<T: ?Sized> Borrowable for alloc::boxed ::Box <T> { type Abi = NonNull<T>; } // ❌ UB? See try_to_break_box_valrows below, and use BoxRow instead.
     unsafe impl<T: ?Sized> Borrowable for alloc::rc    ::Rc  <T> { type Abi = NonNull<T>; }
     unsafe impl<T: ?Sized> Borrowable for alloc::rc    ::Weak<T> { type Abi = NonNull<T>; }
     unsafe impl<T: ?Sized> Borrowable for alloc::sync  ::Arc <T> { type Abi = NonNull<T>; }
@@ -158,6 +168,16 @@ unsafe impl<T: ?Sized> Borrowable for NonNull<T>    { type Abi = NonNull<T>;
 /// [should be able to catch such bugs](https://github.com/rust-lang/rust/pull/94421#issuecomment-1113992481),
 /// but I haven't been able to convince my copy to catch any bugs in this test.
 ///
+/// ### Conclusion
+/// This test passes under both Stacked Borrows and Tree Borrows, but only because it never materializes a second `Box` value:
+/// [`Valrow`](crate::Valrow) copies `NonNull<T>`, and `&Box<T>` isn't retagged through.
+/// A typed copy of the `Box` (e.g. `core::ptr::read(&a)`, or a `Valrow<Box<T>>` substituted for an owned `Box<T>` at a fn boundary)
+/// *is* reported as UB once the original is used again, under both models.
+/// (`-Zmiri-unique-is-unique` no longer exists: `Box` is always treated as unique by current Miri.)
+///
+/// Since substituting for owned values is the point of [`Borrowable`], `Box` stays unimplemented.
+/// [`BoxRow`](crate::BoxRow) exposes only the operations that are sound regardless: `&'a T`, `as_ptr`, and `Pin<&T>`.
+///
 /// ### Testing
 /// ```cmd
 /// rustup toolchain install nightly -c miri
@@ -165,7 +185,7 @@ unsafe impl<T: ?Sized> Borrowable for NonNull<T>    { type Abi = NonNull<T>;
 /// set RUSTFLAGS=--cfg xxx_borrowable_box
 /// cargo +nightly miri test --all-features
 ///
-/// set MIRIFLAGS=-Zmiri-unique-is-unique -Zmiri-tree-borrows
+/// set MIRIFLAGS=-Zmiri-tree-borrows
 /// cargo +nightly miri test --all-features
 /// ```
 ///
@@ -198,3 +218,22 @@ unsafe impl<T: ?Sized> Borrowable for NonNull<T>    { type Abi = NonNull<T>;
     let fmt = alloc::format!("{:?}", (&a, b, c));
     #[cfg(feature = "std")] std::println!("{fmt}");
 }
+
+/// [`Valrow`](crate::Valrow)s are themselves [`Borrowable`], and can be nested or stored in other [`Borrowable`] types without growing.
+#[test] fn nested_valrows() {
+    use crate::{Valrow, ValrowMut};
+    use core::mem::size_of;
+
+    let a = &42;
+    let b = Valrow::new(&a);
+    let c = Valrow::new(&b); // Valrow<Valrow<&i32>>
+    assert_eq!(***c, 42);
+    assert_eq!(size_of::<Valrow<Valrow<&i32>>>(), size_of::<&i32>());
+    assert_eq!(size_of::<Option<Valrow<Valrow<&i32>>>>(), size_of::<&i32>());
+
+    let mut unit = ();
+    let d = ValrowMut::new(&mut unit);
+    let e = Valrow::new(&d); // Valrow<ValrowMut<()>>
+    let f = e;
+    let _ = (e, f);
+}
@@ -0,0 +1,73 @@
+use core::ptr::NonNull;
+#[cfg(doc)] use crate::*;
+
+
+
+/// A type that is exclusively borrowable by value.
+///
+/// A by-value exclusive borrow (<code>[ValrowMut]\<Self\></code>) effectively has:
+/// *   *shared* (by-value) access to *direct* data (e.g. the address stored in a `&mut T`)
+/// *   *exclusive* access to *indirect* data (e.g. the `T` *pointed to* by a `&mut T`)
+///
+/// Unlike [`Borrowable`], this places no requirement that copies of `Self` may be *shared* between multiple addresses:
+/// only one by-value exclusive borrow is accessible at a time, and the original is inaccessible for the duration of that borrow.
+/// This makes it possible to implement for types such as `&mut T`.
+///
+/// ### Safety
+/// By implementing this trait, you assert that, while the original `Self` is exclusively borrowed,
+/// it's safe and sound for a bitwise copy of `Self` at another address to:
+/// *   be accessed by `&Self`.
+/// *   be accessed by `&mut Self`, if `AbiMut = ()` (and thus `Self` is a ZST.)
+/// *   be discarded without being dropped.
+///
+/// Additionally, `Self` and <code>Self::[AbiMut](Self::AbiMut)</code> must be ABI compatible, as with [`Borrowable::Abi`].
+///
+/// [`ValrowMut`] only hands out `&mut Self` when `AbiMut = ()`:
+/// `&mut Self` would permit e.g. [`core::mem::replace`]ing the copy with another `Self`, moving the copy out of the borrow.
+/// With owning types (boxes, vectors, ...) that frees an allocation the original still owns,
+/// and with `&mut T` that yields a second `&mut T` aliasing the original.
+/// Such types may still implement this trait for shared access to direct data, and exclusive access to indirect data via [`IndirectMut`].
+///
+/// Singleton ZSTs (which [`ValrowMut`] required to be [`Borrowable`] before this trait existed) can implement both traits with [`borrowable_zst!`].
+///
+/// ```rust
+/// # use valrow::*;
+/// let mut value = vec![1, 2, 3];
+/// let mut reference = &mut value;
+/// let mut borrow = IndirectMut::new(&mut reference);  // IndirectMut<&mut Vec<i32>>
+/// borrow.push(4);                                     // indirect data: written to `value`
+/// assert_eq!(*reference, [1, 2, 3, 4]);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use valrow::*;
+/// let mut value = 1;
+/// let mut other = 2;
+/// let mut reference = &mut value;
+/// let mut borrow = ValrowMut::new(&mut reference);    // ValrowMut<&mut i32>
+/// let _ = &*borrow;                                   // ✔️ will compile
+/// let stolen = core::mem::replace(&mut *borrow, &mut other); // ❌ won't compile: `AbiMut` isn't `()`
+/// *stolen = 3;                                        // would alias...
+/// *reference = 4;                                     // ...this
+/// ```
+///
+/// ```rust,compile_fail
+/// # use valrow::*;
+/// # use core::ptr::NonNull;
+/// #[repr(transparent)] struct Owner(NonNull<u32>);    // Drop: `&mut Owner` would be harmful
+/// impl Drop for Owner { fn drop(&mut self) { /* free */ } }
+/// unsafe impl valrow::BorrowableMut for Owner { type AbiMut = NonNull<u32>; } // ✔️ sound, but no DerefMut
+///
+/// let mut owner = Owner(NonNull::dangling());
+/// let mut borrow = ValrowMut::new(&mut owner);
+/// let _ = &*borrow;       // ✔️ will compile
+/// let _ = &mut *borrow;   // ❌ won't compile: `AbiMut` isn't `()`
+/// ```
+///
+pub unsafe trait BorrowableMut {
+    type AbiMut : Copy;
+}
+
+unsafe impl BorrowableMut for ()                                { type AbiMut = ();         }
+unsafe impl<T: ?Sized> BorrowableMut for &mut T                 { type AbiMut = NonNull<T>; }
+unsafe impl<T: ?Sized> BorrowableMut for NonNull<T>             { type AbiMut = NonNull<T>; }
@@ -0,0 +1,32 @@
+use crate::*;
+use core::ops::Deref;
+
+
+
+/// A [`Borrowable`] handle to a `str` that lives outside the handle itself (in a shared, interned, or static allocation.)
+///
+/// This enables <code>[Valrow]::[as_str](Valrow::as_str)\(this\) -> &'a str</code>, which outlives the [`Valrow`] it was projected from.
+///
+/// ### Safety
+/// By implementing this trait, you assert that the `str` returned by <code>[Deref]::deref</code> remains valid for as long as the original `Self` does,
+/// regardless of where (or whether) bitwise copies of `Self` live.
+///
+/// ```rust
+/// # use valrow::*;
+/// #[derive(Clone, Copy)] #[repr(transparent)] struct Interned(&'static str);
+/// impl core::ops::Deref for Interned { type Target = str; fn deref(&self) -> &str { self.0 } }
+/// unsafe impl Borrowable    for Interned { type Abi = Self; }
+/// unsafe impl BorrowableStr for Interned {} // ✔️ sound: the `str` isn't stored inline
+///
+/// let name = Interned("name");
+/// let name : &str = Valrow::as_str(Valrow::new(&name));
+/// assert_eq!(name, "name");
+/// ```
+pub unsafe trait BorrowableStr : Borrowable + Deref<Target = str> {}
+
+unsafe impl BorrowableStr for &'_ str {}
+
+#[cfg(feature = "alloc")] const _ : () = {
+    unsafe impl BorrowableStr for alloc::rc  ::Rc <str> {}
+    unsafe impl BorrowableStr for alloc::sync::Arc<str> {}
+};
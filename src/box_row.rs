@@ -0,0 +1,120 @@
+use crate::*;
+
+use alloc::boxed::Box;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter, Pointer};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::pin::Pin;
+use core::ptr::NonNull;
+
+
+
+/// A by-value borrow of a <code>[Box]\<T\></code>, ABI compatible with <code>[Box]\<T\></code> (and `&T`), exposing only shared access to `T`.
+///
+/// [`Box`] does not implement [`Borrowable`]: a bitwise copy of a [`Box`] is a second [`Box`], which is
+/// [`Unique`](https://github.com/rust-lang/unsafe-code-guidelines/issues/326) (`noalias` when passed by value, `Unique` retags under Miri.)
+/// Under both Stacked Borrows and Tree Borrows, Miri reports UB for a typed copy of a `Box<Cell<_>>` used alongside the original.
+/// <code>[Valrow]\<[Box]\<T\>\></code> happens to pass Miri (see `try_to_break_box_valrows` in `src/borrowable.rs`),
+/// but only because it never materializes a [`Box`] value: substituting it for an owned [`Box`] at an FFI or fn boundary would.
+///
+/// [`BoxRow`] sidesteps the question entirely: it only ever holds a shared-reborrowed `NonNull<T>`, so it's exactly as sound as `&'a T`,
+/// while still being passable where C expects the same pointer a `Box<T>` would have been (e.g. `Box<dyn Trait>` handed to C.)
+/// Operations:
+/// *   <code>[Deref]\<Target = T\></code> (and thus `&'a T` via [`BoxRow::get`])
+/// *   [`BoxRow::as_ptr`] for FFI
+/// *   <code>[Pin]\<[BoxRow]\<T\>\></code> via [`BoxRow::pin`], for borrowing <code>[Pin]\<[Box]\<T\>\></code>s (use [`Pin::as_ref`] for `Pin<&T>`.)
+///
+/// ### Examples
+/// ```
+/// use valrow::*;
+/// use core::cell::Cell;
+///
+/// let a = Box::new(Cell::new(42));
+/// let b = BoxRow::new(&a);
+/// let c = b; // Copy
+/// a.set(1);
+/// b.set(2);
+/// assert_eq!(c.get(), 2);
+/// assert_eq!(BoxRow::as_ptr(b), &*a as *const Cell<i32>);
+/// assert_eq!(core::mem::size_of::<BoxRow<dyn core::any::Any>>(), core::mem::size_of::<Box<dyn core::any::Any>>());
+///
+/// let p = Box::pin(String::from("pinned"));
+/// let r = BoxRow::pin(&p);
+/// let s : core::pin::Pin<&String> = r.as_ref();
+/// assert_eq!(s.len(), 6);
+/// ```
+#[repr(transparent)] pub struct BoxRow<'a, T: ?Sized>(NonNull<T>, PhantomData<&'a T>);
+
+unsafe impl<'a, T: ?Sized           > Send              for BoxRow<'a, T> where &'a T : Send {}
+unsafe impl<'a, T: ?Sized           > Sync              for BoxRow<'a, T> where &'a T : Sync {}
+unsafe impl<'a, T: ?Sized           > Borrowable        for BoxRow<'a, T> { type Abi = NonNull<T>; }
+impl<    T: ?Sized                  > Copy              for BoxRow<'_, T> {}
+impl<    T: ?Sized                  > Clone             for BoxRow<'_, T> { fn clone(&self)                        -> Self             { *self }                                           }
+impl<    T: ?Sized                  > AsRef<T>          for BoxRow<'_, T> { fn as_ref(&self)                       -> &T               { self }                                            }
+impl<    T: ?Sized                  > Borrow<T>         for BoxRow<'_, T> { fn borrow(&self)                       -> &T               { self }                                            }
+impl<    T: ?Sized                  > Deref             for BoxRow<'_, T> { fn deref(&self)                        -> &T               { unsafe { self.0.as_ref() } } type Target = T;     }
+impl<'a, T: ?Sized                  > From<&'a Box<T>>  for BoxRow<'a, T> { fn from(reference: &'a Box<T>) -> Self { Self::new(reference) } }
+
+impl<T: ?Sized + Debug              > Debug             for BoxRow<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Debug       >::fmt(self, fmt) }             }
+impl<T: ?Sized + Display            > Display           for BoxRow<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Display     >::fmt(self, fmt) }             }
+impl<T: ?Sized                      > Pointer           for BoxRow<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <*const T as Pointer>::fmt(&Self::as_ptr(*self), fmt) } }
+impl<T: ?Sized + PartialEq          > PartialEq         for BoxRow<'_, T> { fn eq(&self, other: &Self)             -> bool             { <T as PartialEq   >::eq(self, other) }            }
+impl<T: ?Sized + Eq                 > Eq                for BoxRow<'_, T> {}
+impl<T: ?Sized + PartialOrd         > PartialOrd        for BoxRow<'_, T> { fn partial_cmp(&self, other: &Self)    -> Option<Ordering> { <T as PartialOrd  >::partial_cmp(self, other) }   }
+impl<T: ?Sized + Ord                > Ord               for BoxRow<'_, T> { fn cmp(&self, other: &Self)            -> Ordering         { <T as Ord         >::cmp(self, other) }           }
+impl<T: ?Sized + Hash               > Hash              for BoxRow<'_, T> { fn hash<H: Hasher>(&self, state: &mut H)                   { <T as Hash        >::hash(self, state) }          }
+#[cfg(feature = "serde")] impl<T: ?Sized + serde::Serialize> serde::Serialize for BoxRow<'_, T> { fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { <T as serde::Serialize>::serialize(self, serializer) } }
+
+impl<'a, T: ?Sized> BoxRow<'a, T> {
+    /// Borrow `*reference` by value.
+    ///
+    /// This reborrows `&**reference` rather than bitwise copying the [`Box`], so no second `Box` ever exists.
+    #[allow(clippy::borrowed_box)] // borrowing the Box itself is the point
+    #[inline(always)] pub fn new(reference: &'a Box<T>) -> Self { Self(NonNull::from(&**reference), PhantomData) }
+
+    /// Borrow a pinned `*reference` by value.  The result can be projected to <code>[Pin]\<&T\></code> via [`Pin::as_ref`].
+    #[inline(always)] pub fn pin(reference: &'a Pin<Box<T>>) -> Pin<Self> {
+        // SAFETY: the pointee remains pinned for at least as long as `reference` lives, which outlives 'a
+        unsafe { Pin::new_unchecked(Self(NonNull::from(&**reference), PhantomData)) }
+    }
+
+    /// Access the pointee for the entire lifetime `'a` (rather than the lifetime of `this`.)
+    pub fn get(this: Self) -> &'a T { unsafe { this.0.as_ref() } }
+
+    /// The address of the pointee, suitable for handing to FFI expecting a <code>[Box]\<T\></code>'s pointer for shared access.
+    pub fn as_ptr(this: Self) -> *const T { this.0.as_ptr() }
+}
+
+
+
+/// Interleave access through a <code>[Box]\<[Cell](core::cell::Cell)\<_\>\></code> and [`BoxRow`]s.
+/// Run under Miri: see [Testing](crate#testing) (filter: `box_row`.)
+#[test] fn box_row_cell_interleaved() {
+    use core::cell::Cell;
+
+    let a = Box::new(Cell::new(42));
+    let b = BoxRow::new(&a);
+    let c = &a;
+    a.set(1);
+    b.set(2);
+    c.set(3);
+    assert_eq!(b.get(), 3);
+
+    let b : &Cell<i32> = BoxRow::get(b);
+    a.set(4);
+    b.set(5);
+    c.set(6);
+    assert_eq!(b.get(), 6);
+
+    #[inline(never)] fn by_value(v: BoxRow<Cell<i32>>) -> i32 { v.set(7); v.get() }
+    assert_eq!(by_value(BoxRow::new(&a)), 7);
+    assert_eq!(a.get(), 7);
+
+    let d : Box<dyn Fn() -> i32> = Box::new(move || 8);
+    let e = BoxRow::new(&d);
+    assert_eq!(e(), 8);
+    assert_eq!(BoxRow::as_ptr(e) as *const u8, &*d as *const dyn Fn() -> i32 as *const u8);
+}
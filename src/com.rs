@@ -0,0 +1,119 @@
+//! COM-style intrusively refcounted interface pointers: [`ComPtr`], [`Interface`], [`IUnknownVtbl`].
+//!
+//! This models just enough of COM to make the motivating [Direct3D 11](crate::Examples::Direct3D11) case expressible (and testable) without `windows` or Windows:
+//! arrays of <code>[Option]\<[Valrow]\<[ComPtr]\<I\>\>\></code>s are ABI compatible with the `I* const*` arrays taken by APIs like `PSSetShaderResources`,
+//! and passing them around never calls `AddRef` or `Release`.
+//! See `tests/com.rs` for a mock `IUnknown` implementation and a fake `PSSetShaderResources`.
+
+use crate::*;
+
+use core::ffi::c_void;
+use core::fmt::{self, Debug, Formatter, Pointer};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+use core::ptr::NonNull;
+
+
+
+/// A COM `GUID` (or `IID`.)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)] pub struct Guid {
+    pub data1: u32,
+    pub data2: u16,
+    pub data3: u16,
+    pub data4: [u8; 8],
+}
+
+/// The `IUnknown` vtable, which every COM interface's vtable starts with.
+#[repr(C)] pub struct IUnknownVtbl {
+    /// `HRESULT QueryInterface(REFIID riid, void** ppvObject)`
+    pub query_interface : unsafe extern "system" fn(this: *mut c_void, iid: *const Guid, object: *mut *mut c_void) -> i32,
+
+    /// `ULONG AddRef()`
+    pub add_ref         : unsafe extern "system" fn(this: *mut c_void) -> u32,
+
+    /// `ULONG Release()`
+    pub release         : unsafe extern "system" fn(this: *mut c_void) -> u32,
+}
+
+/// A COM interface (or object) type, pointed to by [`ComPtr`]s.
+///
+/// ### Safety
+/// By implementing this trait, you assert that every `I` a [`ComPtr<I>`] could point to:
+/// *   Starts with a pointer to a vtable, which itself starts with an [`IUnknownVtbl`].
+/// *   Implements `AddRef` and `Release` with COM semantics (`Release` frees the object once the last reference is released.)
+/// *   Tolerates `AddRef` and `Release` while `&I`s exist: either the refcount lives behind an `UnsafeCell` (e.g. an `AtomicU32`),
+///     or it lives outside of the first `size_of::<I>()` bytes (e.g. an opaque `#[repr(C)] struct ID3D11ShaderResourceView { vtbl: *const ... }`.)
+///
+/// `AddRef` and `Release` may be called from whatever threads `Send`/`Sync` allow [`ComPtr<I>`]s to be used on.
+pub unsafe trait Interface {}
+
+/// An owning, intrusively refcounted pointer to a COM interface `I`: calls `AddRef` on [`Clone`], and `Release` on [`Drop`].
+///
+/// [`ComPtr`] is [`Borrowable`] with `Abi = NonNull<I>`, so <code>[Valrow]\<[ComPtr]\<I\>\></code> is ABI compatible with a non-null `I*`,
+/// and <code>[Option]\<[Valrow]\<[ComPtr]\<I\>\>\></code> with a nullable one.
+/// Operations are [associated functions](crate#associated-functions) (e.g. [`ComPtr::as_raw`]), since `ComPtr` [`Deref`]s to `I`.
+///
+/// [`ComPtr<I>`] is [`Send`] / [`Sync`] only when `I` is both [`Send`] and [`Sync`] (`AddRef`/`Release` must then be thread safe.)
+#[repr(transparent)] pub struct ComPtr<I: Interface>(NonNull<I>, PhantomData<I>);
+
+unsafe impl<I: Interface + Send + Sync> Send for ComPtr<I> {}
+unsafe impl<I: Interface + Send + Sync> Sync for ComPtr<I> {}
+
+// Copies of a `ComPtr` share its single reference: only the original ever calls `Release`.
+unsafe impl<I: Interface> Borrowable for ComPtr<I> { type Abi = NonNull<I>; }
+
+impl<I: Interface> ComPtr<I> {
+    /// Take ownership of one existing reference to `*raw` (does not call `AddRef`.)
+    ///
+    /// ### Safety
+    /// `raw` must point to a live `I` that the caller owns a reference to, which is transferred to the resulting [`ComPtr`].
+    pub const unsafe fn from_raw(raw: NonNull<I>) -> Self { Self(raw, PhantomData) }
+
+    /// Give up ownership of `this`'s reference (without calling `Release`.)  See [`ComPtr::from_raw`].
+    pub fn into_raw(this: Self) -> NonNull<I> { ManuallyDrop::new(this).0 }
+
+    /// Get the interface pointer of `this`, without touching its refcount.
+    pub fn as_raw(this: &Self) -> NonNull<I> { this.0 }
+
+    fn vtbl(&self) -> &IUnknownVtbl { unsafe { &**self.0.as_ptr().cast::<*const IUnknownVtbl>() } }
+}
+
+impl<I: Interface> Clone for ComPtr<I> {
+    fn clone(&self) -> Self {
+        unsafe { (self.vtbl().add_ref)(self.0.as_ptr().cast()) };
+        Self(self.0, PhantomData)
+    }
+}
+
+impl<I: Interface> Drop for ComPtr<I> {
+    fn drop(&mut self) { unsafe { (self.vtbl().release)(self.0.as_ptr().cast()) }; }
+}
+
+impl<I: Interface> Deref        for ComPtr<I> { fn deref(&self)                       -> &I           { unsafe { self.0.as_ref() } } type Target = I; }
+impl<I: Interface> Debug        for ComPtr<I> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result  { write!(fmt, "ComPtr({:?})", self.0) } }
+impl<I: Interface> Pointer      for ComPtr<I> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result  { Pointer::fmt(&self.0, fmt) } }
+impl<I: Interface> PartialEq    for ComPtr<I> { fn eq(&self, other: &Self)             -> bool         { self.0 == other.0 } }
+impl<I: Interface> Eq           for ComPtr<I> {}
+impl<I: Interface> Hash         for ComPtr<I> { fn hash<H: Hasher>(&self, state: &mut H)              { self.0.hash(state) } }
+
+impl<'a, I: Interface> Valrow<'a, ComPtr<I>> {
+    /// Get the interface pointer of `this`, without touching its refcount.
+    pub fn as_raw(this: Self) -> NonNull<I> { ComPtr::as_raw(&this) }
+
+    /// Reinterpret a slice of borrowed interfaces as a slice of `I*`s (e.g. for an `I* const*` + count parameter), without touching any refcounts.
+    pub fn as_raw_slice(slice: &[Self]) -> &[*mut I] {
+        unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) }
+    }
+
+    /// Reinterpret a slice of optionally borrowed interfaces as a slice of nullable `I*`s, without touching any refcounts.
+    ///
+    /// This is exactly the shape of e.g. `ID3D11DeviceContext::PSSetShaderResources(StartSlot, NumViews, ppShaderResourceViews)`,
+    /// where `nullptr` entries unbind their slots.
+    pub fn as_raw_option_slice(slice: &[Option<Self>]) -> &[*mut I] {
+        // `Option<Valrow<ComPtr<I>>>` is `Option<NonNull<I>>`, which is guaranteed to be a nullable `*mut I`.
+        unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) }
+    }
+}
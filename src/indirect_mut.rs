@@ -0,0 +1,105 @@
+use crate::*;
+
+use core::fmt::{self, Debug, Display, Formatter};
+use core::marker::PhantomData;
+use core::mem::transmute;
+use core::ops::{Deref, DerefMut};
+
+
+
+/// A type whose *indirect* data (<code>[Deref]::[Target](Deref::Target)</code>) can be exclusively borrowed through a by-value borrow.
+///
+/// ### Safety
+/// In addition to the requirements of [`BorrowableMut`], by implementing this trait you assert that:
+/// *   [`DerefMut::deref_mut`] may be called on a bitwise copy of `Self`, while the original `Self` is exclusively borrowed.
+/// *   [`DerefMut::deref_mut`] doesn't modify direct data (no copy-on-write reallocations, no lazy initialization, ...)
+/// *   The returned `&mut Self::Target` refers to indirect data exclusively owned by the original `Self`.
+pub unsafe trait BorrowableIndirectMut : BorrowableMut + DerefMut {}
+
+unsafe impl<T: ?Sized> BorrowableIndirectMut for &mut T {}
+
+
+
+/// A by-value exclusive borrow of an owner `P`, granting `&mut P::Target`, but only `&P`.
+///
+/// This is the reusable form of the hand-written `ABoxMut` from the [`ValrowMut`] docs:
+/// [`DerefMut`] goes straight to <code>P::[Target](Deref::Target)</code>, so it's impossible to use it to replace the owner itself.
+/// Access to the owner (allocator, metadata, ...) is limited to <code>[IndirectMut::owner]\(&self\) -> &P</code>.
+///
+/// ### Examples
+/// ```
+/// use valrow::*;
+///
+/// let mut value = vec![1, 2, 3];
+/// let mut reference = &mut value;
+/// let mut borrow = IndirectMut::new(&mut reference);
+/// borrow.push(4);                                     // `&mut Vec<_>` via `&mut &mut Vec<_>`
+/// assert_eq!(IndirectMut::owner(&borrow).len(), 4);
+/// *borrow = vec![5, 6];                              // replaces `value` (indirect data), not `reference` (direct data)
+/// assert_eq!(value, [5, 6]);
+/// ```
+#[repr(transparent)] pub struct IndirectMut<'a, P: BorrowableIndirectMut>(P::AbiMut, PhantomData<&'a mut P>);
+
+unsafe impl<'a, P: BorrowableIndirectMut    > Send                  for IndirectMut<'a, P> where &'a mut P : Send {}
+unsafe impl<'a, P: BorrowableIndirectMut    > Sync                  for IndirectMut<'a, P> where &'a mut P : Sync {}
+impl<    P: BorrowableIndirectMut           > AsRef<P::Target>      for IndirectMut<'_, P> { fn as_ref(&    self) -> &    P::Target { self }                          }
+impl<    P: BorrowableIndirectMut           > AsMut<P::Target>      for IndirectMut<'_, P> { fn as_mut(&mut self) -> &mut P::Target { self }                          }
+impl<    P: BorrowableIndirectMut           > Deref                 for IndirectMut<'_, P> { fn deref    (&    self) -> &    P::Target { <P as Deref   >::deref    (Self::owner    (self)) } type Target = P::Target; }
+impl<    P: BorrowableIndirectMut           > DerefMut              for IndirectMut<'_, P> { fn deref_mut(&mut self) -> &mut P::Target { <P as DerefMut>::deref_mut(Self::owner_mut(self)) }                          }
+impl<'a, P: BorrowableIndirectMut           > From<&'a mut P>       for IndirectMut<'a, P> { fn from(reference: &'a mut P) -> Self { Self::new(reference) } }
+
+impl<P: BorrowableIndirectMut> Debug   for IndirectMut<'_, P> where P::Target : Debug   { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result { <P::Target as Debug  >::fmt(self, fmt) } }
+impl<P: BorrowableIndirectMut> Display for IndirectMut<'_, P> where P::Target : Display { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result { <P::Target as Display>::fmt(self, fmt) } }
+
+impl<'a, P: BorrowableIndirectMut> IndirectMut<'a, P> {
+    /// Borrow `*reference` by value, granting exclusive access to its indirect data.
+    #[inline(always)] pub fn new(reference: &'a mut P) -> Self {
+        let _ = Self::STATIC_CHECK_P_ABI;
+        Self(unsafe { *transmute::<&mut P, &mut P::AbiMut>(reference) }, PhantomData)
+    }
+
+    /// Shared access to the borrowed owner (e.g. for allocator or metadata access.)
+    pub fn owner(this: &Self) -> &P {
+        let _ = Self::STATIC_CHECK_P_ABI;
+        unsafe { transmute(this) }
+    }
+
+    /// Shared by-value access to the borrowed owner, for the lifetime of the borrow of `this`.
+    pub fn valrow(this: &Self) -> Valrow<'_, P> where P : Borrowable { Valrow::new(Self::owner(this)) }
+
+    /// ⚠️ Never expose this: `&mut P` would allow replacing the owner itself.
+    fn owner_mut(this: &mut Self) -> &mut P {
+        let _ = Self::STATIC_CHECK_P_ABI;
+        unsafe { transmute(this) }
+    }
+
+    const STATIC_CHECK_P_ABI : () = static_check_abi::<P, P::AbiMut>();
+}
+
+
+
+/// A by-value exclusive borrow of an <code>[ABox](ialloc::boxed::ABox)\<T, A\></code>: `&mut T`, but only `&A`.  Requires `feature = "ialloc"`.
+///
+/// This is the hand-written `ABoxMut` sketch from the [`ValrowMut`] docs, shipped as an [`IndirectMut`].
+///
+/// ### Examples
+/// ```
+/// # #[cfg(feature = "ialloc")] {
+/// use valrow::*;
+/// use ialloc::allocator::alloc::Global;
+/// use ialloc::boxed::ABox;
+///
+/// let mut value = ABox::new_in(1, Global);
+/// let mut borrow : ABoxMut<i32, Global> = IndirectMut::new(&mut value);
+/// *borrow = 2;                                            // `&mut i32`
+/// let _ : &Global = ABoxMut::allocator(&borrow);          // `&A`
+/// let _ : Valrow<ABox<i32, Global>> = ABoxMut::valrow(&borrow);
+/// assert_eq!(*value, 2);
+/// # }
+/// ```
+#[cfg(feature = "ialloc")] pub type ABoxMut<'b, T, A> = IndirectMut<'b, ialloc::boxed::ABox<T, A>>;
+
+#[cfg(feature = "ialloc")] impl<'b, T: ?Sized, A: ialloc::traits::fat::Free> IndirectMut<'b, ialloc::boxed::ABox<T, A>> {
+    /// Shared access to the [`ABox`](ialloc::boxed::ABox)'s allocator.
+    pub fn allocator(this: &Self) -> &A { ialloc::boxed::ABox::allocator(Self::owner(this)) }
+}
@@ -0,0 +1,38 @@
+//! `feature = "abi_stable"`: [`Valrow`] and [`ValrowMut`] implement `StableAbi` by delegating to <code>T::[Abi](Borrowable::Abi)</code> / <code>T::[AbiMut](BorrowableMut::AbiMut)</code>,
+//! so they can appear in `#[sabi]` signatures.  `abi_stable::std_types::RArc` is also [`Borrowable`]:
+//! see `tests/abi_stable.rs` for a plugin taking <code>[Valrow]\<RArc\<_\>\></code>s.
+//!
+//! ```
+//! fn stable_abi<T: abi_stable::StableAbi>() {}
+//! stable_abi::<valrow::Valrow<abi_stable::std_types::RArc<i32>>>();
+//! ```
+
+use crate::*;
+
+use abi_stable::abi_stability::GetStaticEquivalent_;
+use abi_stable::std_types::RArc;
+use abi_stable::type_layout::TypeLayout;
+use abi_stable::StableAbi;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+
+
+// FFI layouts are exactly those of `T::Abi` / `T::AbiMut` (`#[repr(transparent)]`.)
+unsafe impl<T: Borrowable   > GetStaticEquivalent_  for Valrow   <'_, T> where T::Abi    : StableAbi { type StaticEquivalent = <T::Abi    as GetStaticEquivalent_>::StaticEquivalent; }
+unsafe impl<T: BorrowableMut> GetStaticEquivalent_  for ValrowMut<'_, T> where T::AbiMut : StableAbi { type StaticEquivalent = <T::AbiMut as GetStaticEquivalent_>::StaticEquivalent; }
+unsafe impl<T: Borrowable   > StableAbi             for Valrow   <'_, T> where T::Abi    : StableAbi {
+    type IsNonZeroType = <T::Abi as StableAbi>::IsNonZeroType;
+    const LAYOUT : &'static TypeLayout = <T::Abi as StableAbi>::LAYOUT;
+}
+unsafe impl<T: BorrowableMut> StableAbi             for ValrowMut<'_, T> where T::AbiMut : StableAbi {
+    type IsNonZeroType = <T::AbiMut as StableAbi>::IsNonZeroType;
+    const LAYOUT : &'static TypeLayout = <T::AbiMut as StableAbi>::LAYOUT;
+}
+
+unsafe impl<T> Borrowable for RArc<T> { type Abi = RArcAbi<T>; }
+
+/// `RArc<T>` is `#[repr(C)] { data: *const T, vtable: PrefixRef<...>, PhantomData<T> }`, but the vtable type isn't public.
+#[repr(C)] #[derive(StableAbi)] pub struct RArcAbi<T> { data: *const T, vtable: NonNull<()>, _marker: PhantomData<T> }
+impl<T> Copy  for RArcAbi<T> {}
+impl<T> Clone for RArcAbi<T> { fn clone(&self) -> Self { *self } }
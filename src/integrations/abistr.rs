@@ -0,0 +1,40 @@
+//! `feature = "abistr"` (or `"abistr_0_2"` for its UTF-16/32 variants): `CStrPtr`s and `CStrNonNull`s are [`Borrowable`],
+//! and <code>[Valrow]\<Rc\<[CStr](core::ffi::CStr)\>\></code>s / <code>[Valrow]\<Arc\<[CStr](core::ffi::CStr)\>\></code>s convert to `CStrNonNull`s without walking the string.
+//!
+//! ```
+//! # #[cfg(all(feature = "abistr", feature = "alloc"))] {
+//! # use valrow::*;
+//! # use std::ffi::CStr;
+//! # use std::sync::Arc;
+//! use abistr::CStrNonNull;
+//! let name : Arc<CStr> = CStr::from_bytes_with_nul(b"example\0").unwrap().into();
+//! let name : CStrNonNull = Valrow::new(&name).into();
+//! let copy : Valrow<CStrNonNull> = Valrow::new(&name);
+//! assert_eq!(copy.to_bytes(), b"example");
+//! # }
+//! ```
+
+use crate::*;
+
+
+
+// Thin, `Copy`able C string pointers are their own ABI
+#[cfg(feature = "abistr")] const _ : () = {
+    unsafe impl<'s> Borrowable for abistr::CStrPtr      <'s> { type Abi = Self; }
+    unsafe impl<'s> Borrowable for abistr::CStrNonNull  <'s> { type Abi = Self; }
+};
+#[cfg(feature = "abistr_0_2")] const _ : () = {
+    // N.B. not generic over `U: Unit`: `CStrPtr<U>: Copy` requires `U::CChar: Copy`, but `U::CChar` is unnameable (sealed trait)
+    unsafe impl<'s> Borrowable for abistr_0_2::CStrPtr      <'s, u8 > { type Abi = Self; }
+    unsafe impl<'s> Borrowable for abistr_0_2::CStrPtr      <'s, u16> { type Abi = Self; }
+    unsafe impl<'s> Borrowable for abistr_0_2::CStrPtr      <'s, u32> { type Abi = Self; }
+    unsafe impl<'s> Borrowable for abistr_0_2::CStrNonNull  <'s, u8 > { type Abi = Self; }
+    unsafe impl<'s> Borrowable for abistr_0_2::CStrNonNull  <'s, u16> { type Abi = Self; }
+    unsafe impl<'s> Borrowable for abistr_0_2::CStrNonNull  <'s, u32> { type Abi = Self; }
+};
+
+// `CStr::as_ptr` doesn't walk the string.  The `Valrow` guarantees the owner outlives `'a`.
+#[cfg(all(feature = "abistr",     feature = "alloc"))] impl<'a> From<Valrow<'a, alloc::rc::Rc<core::ffi::CStr>>>     for abistr::CStrNonNull<'a>        { fn from(borrow: Valrow<'a, alloc::rc::Rc<core::ffi::CStr>>)     -> Self { unsafe { Self::from_ptr_unchecked_unbounded(borrow.as_ptr()) } } }
+#[cfg(all(feature = "abistr",     feature = "alloc"))] impl<'a> From<Valrow<'a, alloc::sync::Arc<core::ffi::CStr>>>  for abistr::CStrNonNull<'a>        { fn from(borrow: Valrow<'a, alloc::sync::Arc<core::ffi::CStr>>)  -> Self { unsafe { Self::from_ptr_unchecked_unbounded(borrow.as_ptr()) } } }
+#[cfg(all(feature = "abistr_0_2", feature = "alloc"))] impl<'a> From<Valrow<'a, alloc::rc::Rc<core::ffi::CStr>>>     for abistr_0_2::CStrNonNull<'a>    { fn from(borrow: Valrow<'a, alloc::rc::Rc<core::ffi::CStr>>)     -> Self { unsafe { Self::from_ptr_unchecked_unbounded(borrow.as_ptr()) } } }
+#[cfg(all(feature = "abistr_0_2", feature = "alloc"))] impl<'a> From<Valrow<'a, alloc::sync::Arc<core::ffi::CStr>>>  for abistr_0_2::CStrNonNull<'a>    { fn from(borrow: Valrow<'a, alloc::sync::Arc<core::ffi::CStr>>)  -> Self { unsafe { Self::from_ptr_unchecked_unbounded(borrow.as_ptr()) } } }
@@ -0,0 +1,30 @@
+//! `feature = "arc-swap"`: [`Valrow::from_guard`] borrows the `Arc` behind an `arc_swap::Guard` (which may not have bumped the refcount either.)
+//! See `tests/arc_swap.rs` for readers passing such [`Valrow`]s around while writers hammer the `ArcSwap`.
+
+use crate::*;
+
+use arc_swap::{Guard, RefCnt};
+use arc_swap::strategy::Strategy;
+
+
+
+// N.B. `arc_swap::Guard` itself isn't `Borrowable`: with the (only public) hybrid strategy, it's a `repr(Rust)` pair of a debt slot and `T`, with no layout we could name as an `Abi`.
+impl<'a, T: Borrowable + RefCnt> Valrow<'a, T> {
+    /// Borrow the `Arc` (or other [`RefCnt`]) protected by an `arc_swap::Guard` by value, without touching its refcount.
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// # use valrow::*;
+    /// use arc_swap::ArcSwap;
+    /// use std::sync::Arc;
+    /// fn total(values: &[Valrow<Arc<i32>>]) -> i32 { values.iter().map(|v| ***v).sum() }
+    ///
+    /// let slots  = [ArcSwap::from_pointee(1), ArcSwap::from_pointee(2)];
+    /// let guards = slots.iter().map(ArcSwap::load).collect::<Vec<_>>();
+    /// let values = guards.iter().map(Valrow::from_guard).collect::<Vec<_>>();
+    /// assert_eq!(total(&values), 3);
+    /// assert_eq!(Arc::strong_count(&slots[0].load_full()), 2); // `slots[0]` + `load_full`
+    /// # }
+    /// ```
+    #[inline(always)] pub fn from_guard<S: Strategy<T>>(guard: &'a Guard<T, S>) -> Self { Self::new(guard) }
+}
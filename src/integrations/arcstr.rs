@@ -0,0 +1,55 @@
+//! `feature = "arcstr"`: `arcstr::ArcStr` and `arcstr::Substr` are [`Borrowable`] and [`BorrowableStr`],
+//! so [`Valrow::as_str`] projects them to `&'a str`s that outlive the [`Valrow`] itself.
+//!
+//! ```
+//! # use valrow::*;
+//! fn log(message: Valrow<arcstr::ArcStr>) -> &str { Valrow::as_str(message) }
+//!
+//! let message = arcstr::ArcStr::from("frame 42");
+//! let substr  = message.substr(6..);
+//! assert_eq!(log(Valrow::new(&message)), "frame 42");
+//! assert_eq!(Valrow::as_str(Valrow::new(&substr)), "42");
+//! assert_eq!(core::mem::size_of::<Valrow<arcstr::ArcStr>>(), core::mem::size_of::<*const u8>());
+//! ```
+
+use crate::*;
+
+use arcstr::{ArcStr, Substr};
+use core::mem::{align_of, size_of, MaybeUninit};
+use core::ptr::NonNull;
+
+
+
+// Their layouts aren't generic, so check them eagerly: upstream layout changes should fail to compile, not just misbehave.
+unsafe impl Borrowable      for ArcStr { type Abi = NonNull<u8>; }
+unsafe impl Borrowable      for Substr { type Abi = SubstrAbi;   }
+unsafe impl BorrowableStr   for ArcStr {}
+unsafe impl BorrowableStr   for Substr {}
+
+/// `Substr` is `#[repr(C)] (ArcStr, Idx, Idx)`, where `Idx` is `u32` or `usize` depending on arcstr's `substr-usize-indices` feature.
+#[repr(C)] #[derive(Clone, Copy)] pub struct SubstrAbi(NonNull<u8>, [MaybeUninit<u8>; size_of::<Substr>() - size_of::<NonNull<u8>>()]);
+
+const _ : () = {
+    assert!(align_of::<       ArcStr >() == align_of::<       NonNull<u8> >());
+    assert!( size_of::<       ArcStr >() ==  size_of::<       NonNull<u8> >());
+    assert!( size_of::<Option<ArcStr>>() ==  size_of::<Option<NonNull<u8>>>());
+    assert!(align_of::<       Substr >() == align_of::<       SubstrAbi   >());
+    assert!( size_of::<       Substr >() ==  size_of::<       SubstrAbi   >());
+    assert!( size_of::<Option<Substr>>() ==  size_of::<Option<SubstrAbi  >>());
+};
+
+
+
+/// Project `&'a str`s out of short-lived [`Valrow`]s, then use them after the [`Valrow`]s are gone.
+/// Run under Miri: see [Testing](crate#testing) (filter: `borrowable_str`.)
+#[cfg(feature = "alloc")] #[test] fn borrowable_str_outlives_valrow() {
+    #[inline(never)] fn project<T: BorrowableStr>(value: &T) -> &str { Valrow::as_str(Valrow::new(value)) }
+
+    let rc      : alloc::rc::Rc<str> = "rc".into();
+    let arcstr  = ArcStr::from("arcstr");
+    let substr  = arcstr.substr(3..);
+    let literal = arcstr::literal!("literal");
+    let strs = [project(&"str"), project(&rc), project(&arcstr), project(&substr), project(&literal)];
+    assert_eq!(strs, ["str", "rc", "arcstr", "str", "literal"]);
+    assert_eq!(ArcStr::strong_count(&arcstr), Some(2)); // `arcstr` + `substr`, untouched by `Valrow`s
+}
@@ -0,0 +1,25 @@
+//! `feature = "bytemuck"`: [`Valrow`]s are `Zeroable` / `Pod` when both `T` and <code>T::[Abi](Borrowable::Abi)</code> are,
+//! and <code>[Valrow]\<T\></code> is a `TransparentWrapper<T>` when `T` is its own `Abi`, allowing slices of [`Valrow`]s to be cast without `unsafe`.
+//! ([`Valrow::from_slice`] doesn't need this feature.)
+//!
+//! ```
+//! # use valrow::*;
+//! #[derive(Clone, Copy)] #[repr(transparent)] struct Id(u32);
+//! unsafe impl Borrowable         for Id { type Abi = Id; }
+//! unsafe impl bytemuck::Zeroable for Id {}
+//! unsafe impl bytemuck::Pod      for Id {}
+//!
+//! let ids = [Id(1), Id(2)];
+//! let valrows : &[Valrow<Id>] = bytemuck::TransparentWrapper::wrap_slice(&ids);
+//! let bytes   : &[u8]         = bytemuck::cast_slice(valrows);
+//! assert_eq!(bytes.len(), 8);
+//! ```
+
+use crate::*;
+
+
+
+// Conjuring a `Valrow<T>` from bytes conjures a `T` (e.g. an I/O-safe handle type with `Abi = RawFd`), so require `T` to qualify too, not just `T::Abi`.
+unsafe impl<T: Borrowable + bytemuck::Zeroable> bytemuck::Zeroable              for Valrow<'_,      T> where T::Abi : bytemuck::Zeroable {}
+unsafe impl<T: Borrowable + bytemuck::Pod     > bytemuck::Pod                   for Valrow<'static, T> where T::Abi : bytemuck::Pod {}
+unsafe impl<T: Borrowable<Abi = T> + 'static  > bytemuck::TransparentWrapper<T> for Valrow<'static, T> {} // `Abi = T` implies `T: Copy`: copies outliving the original are harmless
@@ -0,0 +1,17 @@
+//! `feature = "cxx"`: `cxx::UniquePtr<T>` and `cxx::SharedPtr<T>` are [`Borrowable`].
+//! <code>&[Valrow]\<cxx::SharedPtr\<T\>\></code>s deref to the `&SharedPtr<T>`s cxx passes to C++ as `const std::shared_ptr<T>&`, without cloning (and thus without touching use counts.)
+//! See `tests/cxx_bridge` for a C++ bridge receiving such [`Valrow`]s.
+
+use crate::*;
+
+use core::ffi::c_void;
+use core::mem::MaybeUninit;
+use cxx::{SharedPtr, UniquePtr};
+use cxx::memory::{SharedPtrTarget, UniquePtrTarget};
+
+
+
+// Bitwise copies of `std::unique_ptr` / `std::shared_ptr`s, never destroyed, can be read by C++ as `const std::..._ptr<T>&`s without touching use counts.
+// Unlike `alloc::boxed::Box`, `cxx::UniquePtr` is a `#[repr(C)]` raw pointer without `Unique` (see `try_to_break_box_valrows`.)
+unsafe impl<T: UniquePtrTarget> Borrowable for UniquePtr<T> { type Abi =  MaybeUninit<*mut c_void>;      }
+unsafe impl<T: SharedPtrTarget> Borrowable for SharedPtr<T> { type Abi = [MaybeUninit<*mut c_void>; 2]; }
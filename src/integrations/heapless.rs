@@ -0,0 +1,66 @@
+//! `feature = "heapless"`: heapless's pool `Arc`s and `Box`es are [`Borrowable`].
+//!
+//! heapless 0.8 only provides `pool` on x86 (32-bit) and ARM targets with LL/SC (`ldrex`/`strex`/`clrex`): `build.rs` mirrors its probe as `valrow_heapless_pool`.
+//! Elsewhere (including x86_64 and ARMv5TE/ARMv6 targets without `clrex`), this feature provides nothing.
+
+use crate::*;
+
+use heapless::pool::{arc::{Arc, ArcPool}, boxed::{Box, BoxPool}};
+
+
+
+#[cfg(target_arch = "x86")] type PoolPtr = core::num::NonZeroU64; // 32-bit address + 32-bit ABA tag
+#[cfg(target_arch = "arm")] type PoolPtr = core::ptr::NonNull<()>;
+
+// Unlike `alloc::boxed::Box`, `pool::boxed::Box` doesn't use `Unique` (see `try_to_break_box_valrows`), so copies are as fine as for `ialloc::boxed::ABox`.
+unsafe impl<P: ArcPool> Borrowable for Arc<P> { type Abi = PoolPtr; }
+unsafe impl<P: BoxPool> Borrowable for Box<P> { type Abi = PoolPtr; }
+
+
+
+/// Borrow `heapless` pool [`Arc`](heapless::pool::arc::Arc)s and [`Box`](heapless::pool::boxed::Box)es from static pools.
+/// heapless 0.8 has no `pool` on x86_64, so run this targeting 32-bit x86 (natively on x86_64 Linux hosts, given `g++-multilib` for ialloc's C++):
+///
+/// ```cmd
+/// cargo test --target i686-unknown-linux-gnu --features heapless --lib heapless
+/// ```
+///
+/// And under Miri, with both Stacked Borrows (the default) and Tree Borrows.
+/// heapless's tagged pointers are integer-to-pointer casts, and its treiber stack currently crashes Miri's weak memory emulation:
+///
+/// ```cmd
+/// set MIRIFLAGS=-Zmiri-permissive-provenance -Zmiri-disable-weak-memory-emulation
+/// cargo +nightly miri test --target i686-unknown-linux-gnu --features heapless --lib heapless
+///
+/// set MIRIFLAGS=-Zmiri-permissive-provenance -Zmiri-disable-weak-memory-emulation -Zmiri-tree-borrows
+/// cargo +nightly miri test --target i686-unknown-linux-gnu --features heapless --lib heapless
+/// ```
+#[allow(non_upper_case_globals)] // `arc_pool!` / `box_pool!` name both a type and a static
+#[test] fn heapless_pool_valrows() {
+    use core::ptr::addr_of_mut;
+    use heapless::{arc_pool, box_pool};
+    use heapless::pool::{arc::ArcBlock, boxed::BoxBlock};
+
+    arc_pool!(ArcP: u32);
+    box_pool!(BoxP: u32);
+    static mut ARC_BLOCK : ArcBlock<u32> = ArcBlock::new();
+    static mut BOX_BLOCK : BoxBlock<u32> = BoxBlock::new();
+    ArcP.manage(unsafe { &mut *addr_of_mut!(ARC_BLOCK) });
+    BoxP.manage(unsafe { &mut *addr_of_mut!(BOX_BLOCK) });
+
+    let arc = ArcP.alloc(1).unwrap();
+    let a = Valrow::new(&arc);
+    let b = a;
+    assert_eq!((**a, **b), (1, 1));
+    let c = Arc::clone(&b); // touches the refcount via `b`
+    drop((arc, c));
+    assert!(ArcP.alloc(2).is_ok(), "block should have been returned to the pool");
+
+    let mut boxed = BoxP.alloc(3).unwrap();
+    *boxed += 1;
+    let a = Valrow::new(&boxed);
+    let b = a;
+    assert_eq!((**a, **b), (4, 4));
+    *boxed += 1;
+    assert_eq!(*boxed, 5);
+}
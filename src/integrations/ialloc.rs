@@ -0,0 +1,41 @@
+//! `feature = "ialloc"`: <code>ialloc::boxed::ABox\<T, A\></code> is [`Borrowable`], [`BorrowableMut`], and [`BorrowableIndirectMut`] (see [`ABoxMut`]),
+//! and <code>ialloc::vec::AVec\<T, A\></code> is [`ShallowBorrowable`] for `Copy` allocators.
+
+use crate::*;
+
+use core::ptr::NonNull;
+use ialloc::boxed::ABox;
+use ialloc::traits::fat::Free;
+use ialloc::vec::AVec;
+
+
+
+// `ABox` doesn't use `Unique`, so copies are fine.  Valrow's static checks reject non-ZST allocators.
+unsafe impl<T: ?Sized, A: Free> Borrowable              for ABox<T, A> { type Abi    = NonNull<T>; }
+unsafe impl<T: ?Sized, A: Free> BorrowableMut           for ABox<T, A> { type AbiMut = NonNull<T>; } // `ValrowMut`'s ZST check keeps `DerefMut` away from the owner itself: see `IndirectMut` / `ABoxMut` instead.
+unsafe impl<T: ?Sized, A: Free> BorrowableIndirectMut   for ABox<T, A> {}
+
+// `AVec` holds an `ABox` (`NonNull` based, no `Unique`.)  `A: Copy` excludes allocators with direct interior mutability, which copies would desync.
+unsafe impl<T, A: Free + Copy> ShallowBorrowable for AVec<T, A> {}
+
+
+
+#[test] fn shallow_copy_avec_cell_interleaved() {
+    use core::cell::Cell;
+    use ialloc::allocator::alloc::Global;
+
+    let mut a = AVec::<Cell<i32>, Global>::with_capacity(1);
+    a.push(Cell::new(42));
+    {
+        let b = ShallowCopy::new(&a);
+        let c = b.clone();
+        a[0].set(1);
+        b[0].set(2);
+        assert_eq!(a[0].get(), 2);
+        c[0].set(3);
+        assert_eq!(b[0].get(), 3);
+        assert_eq!((a.len(), b.len(), c.capacity()), (1, 1, a.capacity()));
+    }
+    a.push(Cell::new(4)); // may reallocate: fine, the copies are gone
+    assert_eq!(a[1].get(), 4);
+}
@@ -0,0 +1,19 @@
+//! Optional integrations with other crates, each enabled by a feature named after the crate.
+//!
+//! These modules only contain trait impls (and a few associated functions on [`Valrow`](crate::Valrow)):
+//! their docs describe what each feature adds.
+
+#[cfg(feature = "abi_stable")]                          pub mod abi_stable;
+#[cfg(any(feature = "abistr", feature = "abistr_0_2"))] pub mod abistr;
+#[cfg(feature = "arc-swap")]                            pub mod arc_swap;
+#[cfg(feature = "arcstr")]                              pub mod arcstr;
+#[cfg(feature = "bytemuck")]                            pub mod bytemuck;
+#[cfg(feature = "cxx")]                                 pub mod cxx;
+#[cfg(all(feature = "heapless", valrow_heapless_pool))] pub mod heapless;
+#[cfg(feature = "ialloc")]                              pub mod ialloc;
+#[cfg(feature = "pyo3")]                                pub mod pyo3;
+#[cfg(feature = "safer_ffi")]                           pub mod safer_ffi;
+#[cfg(feature = "servo_arc")]                           pub mod servo_arc;
+#[cfg(feature = "triomphe")]                            pub mod triomphe;
+#[cfg(feature = "ustr")]                                pub mod ustr;
+#[cfg(feature = "zerocopy")]                            pub mod zerocopy;
@@ -0,0 +1,23 @@
+//! `feature = "pyo3"`: `pyo3::Py<T>` is [`Borrowable`], and [`Valrow::bind_borrowed`] converts to a `pyo3::Borrowed<'a, 'py, T>` given a `Python<'py>` token.
+//! Neither touches the refcount, so neither needs the GIL to copy.  See `tests/pyo3.rs` for examples using an embedded interpreter.
+
+use crate::*;
+
+use core::mem::transmute;
+use core::ptr::NonNull;
+use pyo3::{Borrowed, Py, Python};
+
+
+
+// `Py<T>` is a `#[repr(transparent)]` strong reference: copies are fine to read (with the GIL), as long as only the original is ever decref'd.
+unsafe impl<T> Borrowable for Py<T> { type Abi = NonNull<pyo3::ffi::PyObject>; }
+
+impl<'a, T> Valrow<'a, Py<T>> {
+    /// Borrow the object for the entire lifetime `'a` (rather than the lifetime of `this`), without touching its refcount.
+    ///
+    /// Use <code>[Borrowed::to_owned]</code> for an owned `Bound<'py, T>`, or `Py::bind` (via [`Deref`](core::ops::Deref)) for a shorter lived `&Bound<'py, T>`.
+    pub fn bind_borrowed<'py>(this: Self, py: Python<'py>) -> Borrowed<'a, 'py, T> {
+        // SAFETY: `Borrowed` holds a copy of the pointer, not a reference to `this`, and the original `Py<T>` outlives 'a.
+        unsafe { transmute::<Borrowed<'_, 'py, T>, Borrowed<'a, 'py, T>>(Py::bind_borrowed(&*this, py)) }
+    }
+}
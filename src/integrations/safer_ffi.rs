@@ -0,0 +1,24 @@
+//! `feature = "safer_ffi"`: [`Valrow`] and [`ValrowMut`] implement `ReprC` by delegating to <code>T::[Abi](Borrowable::Abi)</code> / <code>T::[AbiMut](BorrowableMut::AbiMut)</code>,
+//! so they can appear in `#[ffi_export]` signatures.
+//!
+//! ```
+//! fn repr_c<T: safer_ffi::layout::ReprC>() {}
+//! repr_c::<valrow::Valrow<&i32>>();
+//! repr_c::<valrow::ValrowMut<&mut i32>>();
+//! ```
+
+use crate::*;
+
+use safer_ffi::layout::ReprC;
+
+
+
+// FFI layouts are exactly those of `T::Abi` / `T::AbiMut` (`#[repr(transparent)]`.)
+unsafe impl<T: Borrowable   > ReprC for Valrow   <'_, T> where T::Abi    : ReprC {
+    type CLayout = <T::Abi as ReprC>::CLayout;
+    fn is_valid(it: &Self::CLayout) -> bool { <T::Abi as ReprC>::is_valid(it) }
+}
+unsafe impl<T: BorrowableMut> ReprC for ValrowMut<'_, T> where T::AbiMut : ReprC {
+    type CLayout = <T::AbiMut as ReprC>::CLayout;
+    fn is_valid(it: &Self::CLayout) -> bool { <T::AbiMut as ReprC>::is_valid(it) }
+}
@@ -0,0 +1,23 @@
+//! `feature = "servo_arc"`: `servo_arc::Arc` (and thus `servo_arc::ThinArc`, a type alias) is [`Borrowable`].
+//! See [`triomphe`](super::triomphe) (of which servo_arc is a fork) for an example of code generic over [`Valrow`]s of any kind of `Arc`.
+
+use crate::*;
+
+use core::ptr::NonNull;
+use servo_arc::Arc;
+
+
+
+unsafe impl<T: ?Sized> Borrowable for Arc<T> { type Abi = NonNull<T>; }
+
+
+
+/// Exercise `servo_arc`'s [`Borrowable`] impl (and thus [`Valrow`]'s static layout checks.)
+/// Run under Miri: see [Testing](crate#testing) (filter: `servo_arc`.)
+#[test] fn servo_arc_valrows() {
+    let servo = Arc::new("servo");
+    let a = Valrow::new(&servo);
+    let b = a;
+    assert_eq!((**a, **b), ("servo", "servo"));
+    assert!(Arc::is_unique(&servo));
+}
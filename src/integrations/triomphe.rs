@@ -0,0 +1,61 @@
+//! `feature = "triomphe"`: triomphe's `Arc`, `OffsetArc`, and `ThinArc` are [`Borrowable`], so code generic over <code>[Valrow]\<A\></code> can accept any kind of `Arc`.
+//! <code>[Valrow]\<triomphe::Arc\<T\>\></code>s also convert into `triomphe::ArcBorrow<T>`s (but not back: `ArcBorrow`s don't carry provenance over the refcount.)
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! # use valrow::*;
+//! use core::ops::Deref;
+//! use triomphe::ArcBorrow;
+//! fn sum<A: Borrowable + Deref<Target = [i32]>>(values: Valrow<A>) -> i32 { values.iter().sum() }
+//!
+//! let std_arc : std::sync::Arc<[i32]> = vec![1, 2, 3].into();
+//! let tri_arc : triomphe::Arc<[i32]> = (&[4, 5, 6][..]).into();
+//! assert_eq!(sum(Valrow::new(&std_arc)), 6);
+//! assert_eq!(sum(Valrow::new(&tri_arc)), 15);
+//!
+//! let arc = triomphe::Arc::new(42);
+//! let borrow : ArcBorrow<i32> = Valrow::new(&arc).into();
+//! assert_eq!(*ArcBorrow::get(&borrow), 42);
+//! assert_eq!(triomphe::Arc::count(&arc), 1);
+//! # }
+//! ```
+
+use crate::*;
+
+use core::ptr::NonNull;
+use triomphe::{Arc, ArcBorrow, OffsetArc, ThinArc};
+
+
+
+// Like `alloc::sync::Arc`, these are (possibly fat) pointers to a refcount header followed by `T`.
+// `OffsetArc` instead points directly at `T`, and `ThinArc` is always thin (its length lives in the allocation.)
+unsafe impl<T: ?Sized> Borrowable for Arc      <T   > { type Abi = NonNull<T>;  }
+unsafe impl<T        > Borrowable for OffsetArc<T   > { type Abi = NonNull<T>;  }
+unsafe impl<H, T     > Borrowable for ThinArc  <H, T> { type Abi = NonNull<()>; }
+
+impl<'a, T> From<Valrow<'a, Arc<T>>> for ArcBorrow<'a, T> { fn from(borrow: Valrow<'a, Arc<T>>) -> Self { unsafe { Self::from_ref(&*borrow.as_ptr()) } } }
+// N.B. no `From<ArcBorrow<'a, T>>`: MSRV compatible triomphe `ArcBorrow`s hold a `&'a T`, whose provenance doesn't cover the refcount.
+// Miri reports UB (under both Stacked Borrows and Tree Borrows) for a `Valrow<triomphe::Arc<T>>` rebuilt from one, just as for `ArcBorrow::clone_arc`.
+
+
+
+/// Exercise every `triomphe` [`Borrowable`] impl (and thus [`Valrow`]'s static layout checks), plus `ArcBorrow` conversions.
+/// Run under Miri: see [Testing](crate#testing) (filter: `triomphe`.)
+#[test] fn triomphe_valrows() {
+    let arc = Arc::new(42);
+    let a = Valrow::new(&arc);
+    let b : ArcBorrow<i32> = a.into();
+    assert_eq!((**a, *b, *ArcBorrow::get(&b)), (42, 42, 42));
+    assert_eq!(Arc::count(&arc), 1);
+    let c = Arc::clone(&a); // touches the refcount via `a`
+    assert_eq!(Arc::count(&arc), 2);
+    drop(c);
+
+    let offset : OffsetArc<i32> = Arc::into_raw_offset(arc);
+    assert_eq!(*Valrow::new(&offset), offset);
+
+    let thin : ThinArc<u8, i32> = ThinArc::from_header_and_iter(1, [2, 3].into_iter());
+    let thin_valrow = Valrow::new(&thin);
+    assert_eq!(thin_valrow.header.header, 1);
+    assert_eq!(thin_valrow.slice, [2, 3]);
+}
@@ -0,0 +1,26 @@
+//! `feature = "ustr"`: `ustr::Ustr` is [`Borrowable`] and [`BorrowableStr`].
+//! It's already `Copy`, but C logging layers want the same <code>[Valrow]\<_\></code> signatures as other string handles.
+//!
+//! ```
+//! # use valrow::*;
+//! let ustr = ustr::ustr("interned");
+//! assert_eq!(Valrow::as_str(Valrow::new(&ustr)), "interned");
+//! ```
+
+use crate::*;
+
+use core::mem::{align_of, size_of};
+use core::ptr::NonNull;
+use ustr::Ustr;
+
+
+
+unsafe impl Borrowable      for Ustr { type Abi = Self; }
+unsafe impl BorrowableStr   for Ustr {}
+
+// Its layout isn't generic, so check it eagerly: upstream layout changes should fail to compile, not just misbehave.
+const _ : () = {
+    assert!(align_of::<       Ustr >() == align_of::<       NonNull<u8> >());
+    assert!( size_of::<       Ustr >() ==  size_of::<       NonNull<u8> >());
+    assert!( size_of::<Option<Ustr>>() ==  size_of::<Option<NonNull<u8>>>());
+};
@@ -0,0 +1,14 @@
+//! `feature = "zerocopy"`: [`Valrow`](crate::Valrow) derives zerocopy's `Immutable`, `IntoBytes`, and `KnownLayout`.
+//! The derives only require <code>T::[Abi](crate::Borrowable::Abi)</code> to qualify, not `T` itself:
+//! viewing a [`Valrow`](crate::Valrow) as bytes never conjures a `T` (there's no `FromBytes`.)
+//!
+//! ```
+//! # use valrow::*;
+//! use zerocopy::IntoBytes;
+//! #[repr(transparent)] struct Handle(u32); // not `IntoBytes` (nor even `Copy`)
+//! unsafe impl Borrowable for Handle { type Abi = u32; }
+//!
+//! let handles = [Handle(1), Handle(2)];
+//! let valrows : &[Valrow<Handle>] = Valrow::from_slice(&handles);
+//! assert_eq!(valrows.as_bytes(), 1_u32.as_bytes().iter().chain(2_u32.as_bytes()).copied().collect::<Vec<u8>>());
+//! ```
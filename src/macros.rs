@@ -39,10 +39,147 @@ copyable!(NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsi
 
 
 
+/// <code>borrowable_zst!\(Zst, ...\)</code> &mdash; implements <code>[Borrowable](crate::Borrowable)\<Abi = ()\></code> and <code>[BorrowableMut](crate::BorrowableMut)\<AbiMut = ()\></code> for ZSTs.
+///
+/// This is what [`ValrowMut`](crate::ValrowMut) needs to hand out `&mut Zst`s, and what a [`Valrow`](crate::Valrow) of a [`ValrowMut`](crate::ValrowMut) needs.
+/// Fails to compile if a type isn't a ZST.
+///
+/// ### Examples
+/// ```
+/// use valrow::*;
+///
+/// pub struct Channels(()); // single-instance ZST
+/// valrow::borrowable_zst!(Channels);
+///
+/// let mut channels = Channels(());
+/// let mut borrow = ValrowMut::new(&mut channels);
+/// let _ : &mut Channels = &mut *borrow;
+/// let _ : Valrow<ValrowMut<Channels>> = Valrow::new(&borrow);
+/// ```
+///
+/// ```compile_fail
+/// pub struct NotZst(u8);
+/// valrow::borrowable_zst!(NotZst); // ❌ won't compile: not a ZST
+/// ```
+#[macro_export] macro_rules! borrowable_zst { ( $($ty:ty),* $(,)? ) => { const _ : () = {
+    use $crate::_valrow_macros_prelude::*;
+    $(
+        static_assert::zst::<$ty>();
+        unsafe impl valrow::Borrowable    for $ty { type Abi    = (); }
+        unsafe impl valrow::BorrowableMut for $ty { type AbiMut = (); }
+    )*
+};}}
+
+
+
+/// <code>handle! { pub struct Owned(Raw) => destructor; }</code> &mdash; defines a `#[repr(transparent)]` owned handle, borrowable as <code>[Valrow](crate::Valrow)\<Owned\></code>.
+///
+/// `Raw` is any [`Copy`] type: typically a `NonNull<T>` (`sqlite3*`, `FILE*`, `CURL*`, ...) or a `NonZero*` integer (GL names, 1-based ids, ...),
+/// whose niche gives `Option<Owned>` and <code>[Option]\<[Valrow](crate::Valrow)\<Owned\>\></code> the layout of a nullable `Raw`.
+/// `destructor` is a fn or closure taking `Raw`, called on [`Drop`] (its result, if any, is ignored.)
+///
+/// Rather than defining a separate `Borrowed<'a>` newtype per handle, use <code>[Valrow](crate::Valrow)\<'a, Owned\></code>:
+/// it's ABI compatible with `Raw`, [`Copy`], never runs the destructor, and derefs to `&Owned`,
+/// so `&self` methods of `Owned` (including the generated ones) are callable on both `&Owned`s and <code>[Valrow](crate::Valrow)\<Owned\></code>s.
+/// Generates:
+/// *   `unsafe fn from_raw(raw: Raw) -> Self` (takes ownership)
+/// *   `fn into_raw(self) -> Raw` (gives up ownership, without running `destructor`)
+/// *   `fn as_raw(&self) -> Raw`
+/// *   <code>fn valrow(&self) -> [Valrow](crate::Valrow)\<'_, Self\></code>
+/// *   <code>[Borrowable](crate::Borrowable) for Owned</code> (`Abi = Raw`) and <code>[Drop] for Owned</code>
+///
+/// ### Examples
+/// ```
+/// use valrow::*;
+/// use core::num::NonZeroU32;
+/// use core::ptr::NonNull;
+/// # use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
+/// # #[allow(non_camel_case_types)] pub struct sqlite3(u32);
+/// # static CLOSED : AtomicU32 = AtomicU32::new(0);
+/// # unsafe fn sqlite3_open() -> *mut sqlite3 { Box::into_raw(Box::new(sqlite3(42))) }
+/// # unsafe fn sqlite3_close(db: *mut sqlite3) -> i32 { drop(Box::from_raw(db)); CLOSED.fetch_add(1, Relaxed); 0 }
+/// # unsafe fn sqlite3_changes(db: *mut sqlite3) -> i32 { (*db).0 as i32 }
+/// # unsafe fn gl_delete_texture(_texture: u32) { CLOSED.fetch_add(1, Relaxed); }
+///
+/// valrow::handle! {
+///     /// An owned `sqlite3*`.
+///     #[derive(Debug)] pub struct Database(NonNull<sqlite3>) => |db: NonNull<sqlite3>| unsafe { sqlite3_close(db.as_ptr()) };
+///
+///     /// An owned OpenGL texture name.
+///     pub struct Texture(NonZeroU32) => |texture: NonZeroU32| unsafe { gl_delete_texture(texture.get()) };
+/// }
+///
+/// impl Database {
+///     pub fn open() -> Self { unsafe { Self::from_raw(NonNull::new(sqlite3_open()).unwrap()) } }
+///     pub fn changes(&self) -> i32 { unsafe { sqlite3_changes(self.as_raw().as_ptr()) } }
+/// }
+///
+/// // One borrowed type, ABI compatible with `sqlite3*` (and `Option<Valrow<Database>>` with a nullable one)
+/// fn changes(dbs: &[Option<Valrow<Database>>]) -> i32 { dbs.iter().flatten().map(|db| db.changes()).sum() }
+///
+/// let db = Database::open();
+/// let borrowed : Valrow<Database> = db.valrow();
+/// assert_eq!(borrowed.changes(), db.changes());
+/// assert_eq!(borrowed.as_raw(), db.as_raw());
+/// assert_eq!(changes(&[Some(borrowed), None, Some(borrowed)]), 84);
+/// assert_eq!(core::mem::size_of::<Option<Valrow<Texture>>>(), 4);
+///
+/// let texture = unsafe { Texture::from_raw(NonZeroU32::new(1).unwrap()) };
+/// let _ = Valrow::new(&texture); // doesn't delete the texture
+/// drop(texture);
+/// drop(db);
+/// # assert_eq!(CLOSED.load(Relaxed), 2);
+/// ```
+#[macro_export] macro_rules! handle { ($(
+    $(#[$attr:meta])*
+    $vis:vis struct $name:ident ( $raw:ty ) => $destructor:expr ;
+)*) => {$(
+    $(#[$attr])*
+    #[repr(transparent)] $vis struct $name($raw);
+
+    const _ : () = {
+        use $crate::_valrow_macros_prelude::*;
+        static_assert::copyable::<$raw>();
+        unsafe impl valrow::Borrowable for $name { type Abi = $raw; }
+        impl core::ops::Drop for $name { fn drop(&mut self) { let _ = ($destructor)(self.0); } }
+
+        #[allow(dead_code)] impl $name {
+            /// Take ownership of `raw`, which will be destroyed when the result is dropped.
+            ///
+            /// ### Safety
+            /// `raw` must be a valid handle, owned by the caller, and safe to destroy exactly once.
+            #[inline(always)] $vis const unsafe fn from_raw(raw: $raw) -> Self { Self(raw) }
+
+            /// Give up ownership of the raw handle, without destroying it.
+            #[inline(always)] $vis fn into_raw(self) -> $raw { let raw = self.0; core::mem::forget(self); raw }
+
+            /// Get the raw handle, without giving up ownership.
+            #[inline(always)] $vis fn as_raw(&self) -> $raw { self.0 }
+
+            /// Borrow `self` by value.
+            #[inline(always)] $vis fn valrow(&self) -> valrow::Valrow<'_, Self> { valrow::Valrow::new(self) }
+        }
+    };
+)*}}
+
+
+
 #[doc(hidden)] pub mod _valrow_macros_prelude {
     pub use crate as valrow;
     pub use ::core;
     pub mod static_assert {
         pub const fn copyable<T: Copy>() {}
+        pub const fn zst<T>() { assert!(0 == core::mem::size_of::<T>(), "borrowable_zst!: not a ZST") }
     }
+
+    /// ### Safety
+    /// Do not call this unless a ZST `Z` exists that you should have shared access to.
+    pub unsafe fn zst_ref<'z, Z>() -> &'z Z { let _ = IsZst::<Z>::ASSERT; unsafe { core::ptr::NonNull::<Z>::dangling().as_ref() } }
+
+    /// ### Safety
+    /// Do not call this unless a ZST `Z` exists that you should have exclusive access to.
+    pub unsafe fn zst_mut<'z, Z>() -> &'z mut Z { let _ = IsZst::<Z>::ASSERT; unsafe { core::ptr::NonNull::<Z>::dangling().as_mut() } }
+
+    struct IsZst<Z>(Z);
+    impl<Z> IsZst<Z> { const ASSERT : () = assert!(0 == core::mem::size_of::<Z>(), "Z is not a ZST"); }
 }
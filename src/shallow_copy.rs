@@ -0,0 +1,125 @@
+#[cfg(doc)] use crate::*;
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+use core::ptr;
+
+
+
+/// A type that is borrowable by value via bitwise copy, without needing to name a [`Copy`]able <code>[Borrowable]::[Abi](Borrowable::Abi)</code>.
+///
+/// ### Safety
+/// By implementing this trait, you assert that it's safe and sound for a single instance of `Self` to exist at multiple addresses simultaneously,
+/// exactly as with [`Borrowable`] (no direct interior mutability, no reliance on address identity, ...)
+/// Unlike [`Borrowable`], there's no `Abi` to keep compatible: [`ShallowCopy`] holds a <code>[ManuallyDrop]\<Self\></code>.
+///
+/// Copies are typed `Self` values, which matters for owners built on [`Unique`](https://github.com/rust-lang/unsafe-code-guidelines/issues/326):
+/// under both Stacked Borrows and Tree Borrows, Miri retags typed copies of a [`Box`](alloc::boxed::Box),
+/// after which accessing the allocation through the original `Box` is undefined behavior.
+/// As such, don't implement this for `Box`, or types directly containing a `Box` by value.
+pub unsafe trait ShallowBorrowable {}
+
+// ⚠️ `Vec` and `String` own their buffers through `RawVec`'s `Unique<T>`, so typed copies raise the same open question as `Box`.
+// Current Miri doesn't model `Unique` (only `Box`), so the Miri tests below can't settle it: they only guard against regressions in what Miri does model.
+// These are implemented on the (unresolved) assumption that `Unique` won't gain `Box`'s aliasing requirements.
+#[cfg(feature = "alloc")] const _ : () = {
+    unsafe impl<T> ShallowBorrowable for alloc::vec::Vec<T> {}
+    unsafe impl ShallowBorrowable for alloc::string::String {}
+};
+
+// N.B. `Waker` isn't `Borrowable`: `RawWaker` is `repr(Rust)`, so there's no way to name an `Abi` matching its (nullable data, non-null vtable) field order and niche.
+// Bitwise copies are otherwise harmless (no `Unique`, no interior mutability), so `ShallowCopy<Waker>` passes wakers around without `clone`/`drop` vtable calls.
+unsafe impl ShallowBorrowable for core::task::Waker {}
+
+
+
+/// A by-value borrow, made by bitwise copying `T` into a [`ManuallyDrop`].  Requires <code>T: [ShallowBorrowable]</code>.
+///
+/// Where <code>[Valrow]\<T\></code> requires `T` to name an ABI compatible, [`Copy`]able <code>[Borrowable]::[Abi](Borrowable::Abi)</code>,
+/// [`ShallowCopy`] works with arbitrary layouts (e.g. `Vec`, `String`, `ialloc::vec::AVec`, [`Waker`](core::task::Waker)),
+/// at the cost of not being [`Copy`] itself (only [`Clone`], by another bitwise copy) and not being suitable for FFI.
+///
+/// ### Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use valrow::*;
+///
+/// let value = vec![1, 2, 3];
+/// let a = ShallowCopy::new(&value); // doesn't clone `value`'s elements, nor add any indirection
+/// let b = a.clone();                // doesn't clone `value`'s elements either
+/// assert_eq!(a.len(), 3);
+/// assert_eq!(*b, value);
+/// # }
+/// ```
+#[repr(transparent)] pub struct ShallowCopy<'a, T: ShallowBorrowable>(ManuallyDrop<T>, PhantomData<&'a T>);
+
+unsafe impl<'a, T: ShallowBorrowable    > Send              for ShallowCopy<'a, T> where &'a T : Send {}
+unsafe impl<'a, T: ShallowBorrowable    > Sync              for ShallowCopy<'a, T> where &'a T : Sync {}
+impl<    T: ShallowBorrowable           > Clone             for ShallowCopy<'_, T> { fn clone(&self)                        -> Self             { Self(unsafe { ptr::read(&self.0) }, PhantomData) } }
+impl<    T: ShallowBorrowable           > AsRef<T>          for ShallowCopy<'_, T> { fn as_ref(&self)                       -> &T               { &self.0 }                                          }
+impl<    T: ShallowBorrowable           > Borrow<T>         for ShallowCopy<'_, T> { fn borrow(&self)                       -> &T               { &self.0 }                                          }
+impl<    T: ShallowBorrowable           > Deref             for ShallowCopy<'_, T> { fn deref(&self)                        -> &T               { &self.0 } type Target = T;                         }
+impl<'a, T: ShallowBorrowable           > From<&'a mut T>   for ShallowCopy<'a, T> { fn from(reference: &'a mut T) -> Self { Self::new(reference) } }
+impl<'a, T: ShallowBorrowable           > From<&'a     T>   for ShallowCopy<'a, T> { fn from(reference: &'a     T) -> Self { Self::new(reference) } }
+
+impl<T: ShallowBorrowable + Debug       > Debug             for ShallowCopy<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Debug       >::fmt(self, fmt) }             }
+impl<T: ShallowBorrowable + Display     > Display           for ShallowCopy<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Display     >::fmt(self, fmt) }             }
+impl<T: ShallowBorrowable + PartialEq   > PartialEq         for ShallowCopy<'_, T> { fn eq(&self, other: &Self)             -> bool             { <T as PartialEq   >::eq(self, other) }            }
+impl<T: ShallowBorrowable + PartialEq   > PartialEq<T>      for ShallowCopy<'_, T> { fn eq(&self, other: &T)                -> bool             { <T as PartialEq   >::eq(self, other) }            }
+impl<T: ShallowBorrowable + Eq          > Eq                for ShallowCopy<'_, T> {}
+impl<T: ShallowBorrowable + PartialOrd  > PartialOrd        for ShallowCopy<'_, T> { fn partial_cmp(&self, other: &Self)    -> Option<Ordering> { <T as PartialOrd  >::partial_cmp(self, other) }   }
+impl<T: ShallowBorrowable + PartialOrd  > PartialOrd<T>     for ShallowCopy<'_, T> { fn partial_cmp(&self, other: &T)       -> Option<Ordering> { <T as PartialOrd  >::partial_cmp(self, other) }   }
+impl<T: ShallowBorrowable + Ord         > Ord               for ShallowCopy<'_, T> { fn cmp(&self, other: &Self)            -> Ordering         { <T as Ord         >::cmp(self, other) }           }
+impl<T: ShallowBorrowable + Hash        > Hash              for ShallowCopy<'_, T> { fn hash<H: Hasher>(&self, state: &mut H)                   { <T as Hash        >::hash(self, state) }          }
+#[cfg(feature = "serde")] impl<T: ShallowBorrowable + serde::Serialize> serde::Serialize for ShallowCopy<'_, T> { fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { <T as serde::Serialize>::serialize(self, serializer) } }
+
+impl<'a, T: ShallowBorrowable> ShallowCopy<'a, T> {
+    /// Borrow `*reference` by value.
+    #[inline(always)] pub fn new(reference: &'a T) -> Self {
+        Self(ManuallyDrop::new(unsafe { ptr::read(reference) }), PhantomData)
+    }
+}
+
+
+
+/// Interleave access through the original and [`ShallowCopy`]s, including interior mutability of indirect data.
+/// Run under Miri: see [Testing](crate#testing) (filter: `shallow_copy`.)
+#[cfg(feature = "alloc")] #[test] fn shallow_copy_vec_cell_interleaved() {
+    use alloc::vec;
+    use core::cell::Cell;
+
+    let a = vec![Cell::new(42)];
+    let b = ShallowCopy::new(&a);
+    let c = b.clone();
+    a[0].set(1);
+    b[0].set(2);
+    assert_eq!(a[0].get(), 2);
+    c[0].set(3);
+    a[0].set(4);
+    assert_eq!(b[0].get(), 4);
+    assert_eq!(c[0].get(), 4);
+
+    #[inline(never)] fn by_value(v: ShallowCopy<alloc::vec::Vec<Cell<i32>>>) -> i32 { v[0].set(5); v[0].get() }
+    #[inline(never)] fn by_ref(v: &[Cell<i32>]) -> i32 { v[0].get() }
+    assert_eq!(by_value(b), 5);
+    assert_eq!(by_ref(&a), 5);
+    assert_eq!(by_ref(&c), 5);
+    assert_eq!(a.len(), 1);
+}
+
+#[cfg(feature = "alloc")] #[test] fn shallow_copy_string_interleaved() {
+    let a = alloc::string::String::from("example");
+    let b = ShallowCopy::new(&a);
+    let c = b.clone();
+    assert_eq!(&*a, "example");
+    assert_eq!(*b, a);
+    assert_eq!(&c[..4], "exam");
+    assert_eq!(a.len(), 7);
+    let fmt = alloc::format!("{a} {b} {c}");
+    assert_eq!(fmt, "example example example");
+}
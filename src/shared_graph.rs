@@ -0,0 +1,85 @@
+use crate::*;
+
+use alloc::sync::Arc;
+use core::borrow::Borrow;
+use core::cell::RefCell;
+use serde::ser::{Error as _, Serialize, SerializeStructVariant, Serializer};
+use std::collections::hash_map::{Entry, HashMap};
+
+
+
+std::thread_local! {
+    /// Pointee address → id, of every [`Arc`] serialized so far by [`serialize_shared`] within the innermost [`SharedGraph`].
+    static SHARED_IDS : RefCell<Option<HashMap<usize, usize>>> = const { RefCell::new(None) };
+}
+
+/// Serializes `T`, with [`serialize_shared`] fields deduplicated by pointer identity.  Requires `feature = "serde"` and `feature = "std"`.
+///
+/// Each distinct <code>[Arc]\<U\></code> (or <code>[Valrow]\<[Arc]\<U\>\></code>) pointee is serialized once, as `Def { id, value }`.
+/// Subsequent occurrences of the same pointee serialize as `Ref(id)`, with ids assigned in serialization order starting from `0`.
+///
+/// ### Examples
+/// ```
+/// # #[cfg(all(feature = "serde", feature = "std"))] {
+/// use valrow::*;
+/// use std::sync::Arc;
+///
+/// #[derive(serde::Serialize)] struct Leaf { value: i32 }
+/// #[derive(serde::Serialize)] struct Pair<'a> {
+///     #[serde(serialize_with = "valrow::serialize_shared")] a: Valrow<'a, Arc<Leaf>>,
+///     #[serde(serialize_with = "valrow::serialize_shared")] b: Valrow<'a, Arc<Leaf>>,
+/// }
+///
+/// let leaf = Arc::new(Leaf { value: 42 });
+/// let pair = Pair { a: Valrow::new(&leaf), b: Valrow::new(&leaf) };
+/// let json = serde_json::to_string(&SharedGraph(&pair)).unwrap();
+/// assert_eq!(json, r#"{"a":{"Def":{"id":0,"value":{"value":42}}},"b":{"Ref":0}}"#);
+///
+/// // Without `serialize_shared`, by-value borrows serialize exactly like what they borrow:
+/// assert_eq!(serde_json::to_string(&Valrow::new(&&*leaf)).unwrap(), r#"{"value":42}"#);
+/// assert_eq!(serde_json::to_string(&ValrowDeref::new(&leaf)).unwrap(), r#"{"value":42}"#);
+///
+/// // ...and `serialize_shared` without an enclosing `SharedGraph` is an error:
+/// assert!(serde_json::to_string(&pair).is_err());
+/// # }
+/// ```
+pub struct SharedGraph<'a, T: ?Sized>(pub &'a T);
+
+impl<T: ?Sized + Serialize> Serialize for SharedGraph<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Restore(Option<HashMap<usize, usize>>); // restores the outer graph's ids, even if `T::serialize` panics
+        impl Drop for Restore { fn drop(&mut self) { let outer = self.0.take(); SHARED_IDS.with(|ids| *ids.borrow_mut() = outer) } }
+
+        let _restore = Restore(SHARED_IDS.with(|ids| ids.replace(Some(HashMap::new()))));
+        self.0.serialize(serializer)
+    }
+}
+
+/// Serialize an <code>[Arc]\<T\></code> (or anything that [`Borrow`]s as one, such as <code>[Valrow]\<[Arc]\<T\>\></code>) at most once per [`SharedGraph`].
+///
+/// Intended for use with <code>#\[serde(serialize_with = "valrow::serialize_shared")\]</code>.
+/// Fails with a custom serializer error if there's no enclosing [`SharedGraph`].
+pub fn serialize_shared<A, T, S>(value: &A, serializer: S) -> Result<S::Ok, S::Error> where A : Borrow<Arc<T>>, T : ?Sized + Serialize, S : Serializer {
+    let arc : &Arc<T> = value.borrow();
+    let addr = Arc::as_ptr(arc) as *const () as usize;
+    let id = SHARED_IDS.with(|ids| {
+        let mut ids = ids.borrow_mut();
+        let ids = ids.as_mut()?;
+        let next = ids.len();
+        Some(match ids.entry(addr) {
+            Entry::Occupied(e) => Err(*e.get()),
+            Entry::Vacant(e)   => Ok(*e.insert(next)),
+        })
+    });
+
+    match id {
+        None => Err(S::Error::custom("valrow::serialize_shared requires an enclosing valrow::SharedGraph")),
+        Some(Ok(id)) => {
+            let mut def = serializer.serialize_struct_variant("Shared", 0, "Def", 2)?;
+            def.serialize_field("id",    &id)?;
+            def.serialize_field("value", &**arc)?;
+            def.end()
+        },
+        Some(Err(id)) => serializer.serialize_newtype_variant("Shared", 1, "Ref", &id),
+    }
+}
@@ -0,0 +1,182 @@
+use crate::*;
+
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+use core::mem::{forget, size_of};
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+
+
+/// A lock for a singleton ZST `Z`, with lock state stored in the one [`TypeLock`] per type instead of per address.
+///
+/// The [`Borrowable`] docs warn that ZST mutexes tracking lock state by address (e.g. `HashMap<*const ZstMutex, std::sync::Mutex<()>>`)
+/// break as soon as the ZST is borrowable by value, since every [`Valrow`] is a copy living at a different address.
+/// [`TypeLock`] sidesteps this by never consulting the address of `Z` at all:
+/// consume the single instance of `Z` into a `static TypeLock<Z>`, and all access goes through that one `static`'s lock state.
+/// Rust has no generic `static`s to key that state by type automatically, so [`TypeLock::new`] is `unsafe`: there must only ever be one `TypeLock<Z>` per `Z`.
+/// Locking yields a [`TypeLockGuard`], which hands out <code>[ValrowMut]\<Z\></code>s (or `&mut Z`s) conjured from nothing.
+///
+/// *   With `feature = "std"`, this is backed by a [`std::sync::Mutex<()>`](std::sync::Mutex): blocking, and poisoned by panics while locked.
+/// *   Without `feature = "std"`, this falls back on a spin lock, which never poisons (`no_std` has no means of detecting a panic in progress.)
+///
+/// ### Examples
+/// ```
+/// use valrow::*;
+///
+/// pub struct Channels(()); // single-instance ZST
+/// valrow::borrowable_zst!(Channels);
+///
+/// pub static CHANNELS : TypeLock<Channels> = unsafe { TypeLock::new(Channels(())) }; // the only `TypeLock<Channels>`
+///
+/// {
+///     let mut channels = CHANNELS.lock().unwrap();
+///     let borrow = channels.valrow_mut();     // lives at a different address than `channels`...
+///     let copy = Valrow::new(&*borrow);       // ...as does this...
+///     assert!(CHANNELS.try_lock().is_err());  // ...but there's only one lock state to consult
+/// }
+/// assert!(CHANNELS.try_lock().is_ok());
+/// ```
+///
+/// ```
+/// # use valrow::*;
+/// # pub struct Users(());
+/// # pub static USERS : TypeLock<Users> = unsafe { TypeLock::new(Users(())) };
+/// let _ = std::panic::catch_unwind(|| {
+///     let _users = USERS.lock().unwrap();
+///     panic!("while locked");
+/// });
+/// assert_eq!(USERS.is_poisoned(), cfg!(feature = "std"));
+/// let users = match USERS.try_lock() {
+///     Ok(users) => users,
+///     Err(TypeLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+///     Err(TypeLockError::WouldBlock) => unreachable!(),
+/// };
+/// ```
+pub struct TypeLock<Z> {
+    #[cfg(    feature = "std" )] lock: std::sync::Mutex<()>,
+    #[cfg(not(feature = "std"))] lock: core::sync::atomic::AtomicBool,
+    zst: PhantomData<Z>,
+}
+
+/// An RAII guard granting exclusive access to the singleton ZST `Z` of a [`TypeLock<Z>`].
+pub struct TypeLockGuard<'a, Z> {
+    #[cfg(    feature = "std" )] _guard: std::sync::MutexGuard<'a, ()>,
+    #[cfg(not(feature = "std"))] lock: &'a TypeLock<Z>,
+    zst: PhantomData<&'a mut Z>,
+}
+
+/// A [`TypeLock`] was poisoned by a panic while locked.  Access is still available via [`into_inner`](Self::into_inner).
+pub struct TypeLockPoisoned<G>(G);
+
+/// The error returned by [`TypeLock::try_lock`].
+pub enum TypeLockError<G> {
+    /// The [`TypeLock`] was poisoned by a panic while locked.
+    Poisoned(TypeLockPoisoned<G>),
+
+    /// The [`TypeLock`] is already locked.
+    WouldBlock,
+}
+
+unsafe impl<Z: Send> Sync for TypeLock<Z> {}
+
+impl<Z> Debug for TypeLock<Z>                       { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result { fmt.debug_struct("TypeLock").field("poisoned", &self.is_poisoned()).finish_non_exhaustive() } }
+impl<Z: Debug> Debug for TypeLockGuard<'_, Z>       { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result { <Z as Debug>::fmt(self, fmt) } }
+impl<G> Debug for TypeLockPoisoned<G>               { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result { fmt.write_str("TypeLockPoisoned { .. }") } }
+impl<G> Debug for TypeLockError<G>                  { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result { match self { Self::Poisoned(p) => fmt.debug_tuple("Poisoned").field(p).finish(), Self::WouldBlock => fmt.write_str("WouldBlock") } } }
+impl<G> fmt::Display for TypeLockPoisoned<G>        { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result { fmt.write_str("poisoned lock: another task failed inside") } }
+impl<G> fmt::Display for TypeLockError<G>           { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result { match self { Self::Poisoned(p) => fmt::Display::fmt(p, fmt), Self::WouldBlock => fmt.write_str("try_lock failed because the operation would block") } } }
+impl<G> From<TypeLockPoisoned<G>> for TypeLockError<G> { fn from(poisoned: TypeLockPoisoned<G>) -> Self { Self::Poisoned(poisoned) } }
+#[cfg(feature = "std")] impl<G> std::error::Error for TypeLockPoisoned<G> {}
+#[cfg(feature = "std")] impl<G> std::error::Error for TypeLockError<G> {}
+
+impl<Z> TypeLock<Z> {
+    /// Consume the singleton `value` of `Z`, placing it behind a lock.
+    ///
+    /// `Z` must be a ZST (enforced at compile time.)
+    /// `value` is never dropped: if `Z` implements [`Drop`], that never runs.
+    ///
+    /// ### Safety
+    /// *   This must be the only [`TypeLock<Z>`] ever created (typically by placing it in a `static`.)
+    /// *   `value` must be the only instance of `Z`: no others may exist, now or later, outside of those conjured by [`TypeLockGuard`]s.
+    pub const unsafe fn new(value: Z) -> Self {
+        let _ = Self::STATIC_CHECK_Z_ZST;
+        forget(value); // ownership transfers to the lock state: ZST guards conjure new references to it (so never dropped)
+        Self {
+            #[cfg(    feature = "std" )] lock: std::sync::Mutex::new(()),
+            #[cfg(not(feature = "std"))] lock: core::sync::atomic::AtomicBool::new(false),
+            zst: PhantomData,
+        }
+    }
+
+    /// Acquire the lock, blocking (or spinning, without `feature = "std"`) until it's available.
+    pub fn lock(&self) -> Result<TypeLockGuard<'_, Z>, TypeLockPoisoned<TypeLockGuard<'_, Z>>> {
+        #[cfg(feature = "std")] {
+            match self.lock.lock() {
+                Ok(guard) => Ok(TypeLockGuard::new(guard)),
+                Err(poisoned) => Err(TypeLockPoisoned(TypeLockGuard::new(poisoned.into_inner()))),
+            }
+        }
+        #[cfg(not(feature = "std"))] loop {
+            match self.try_lock() {
+                Err(TypeLockError::WouldBlock) => core::hint::spin_loop(),
+                Err(TypeLockError::Poisoned(poisoned)) => return Err(poisoned),
+                Ok(guard) => return Ok(guard),
+            }
+        }
+    }
+
+    /// Attempt to acquire the lock without blocking.
+    pub fn try_lock(&self) -> Result<TypeLockGuard<'_, Z>, TypeLockError<TypeLockGuard<'_, Z>>> {
+        #[cfg(feature = "std")] {
+            match self.lock.try_lock() {
+                Ok(guard) => Ok(TypeLockGuard::new(guard)),
+                Err(std::sync::TryLockError::Poisoned(poisoned)) => Err(TypeLockError::Poisoned(TypeLockPoisoned(TypeLockGuard::new(poisoned.into_inner())))),
+                Err(std::sync::TryLockError::WouldBlock) => Err(TypeLockError::WouldBlock),
+            }
+        }
+        #[cfg(not(feature = "std"))] {
+            use core::sync::atomic::Ordering::{Acquire, Relaxed};
+            match self.lock.compare_exchange(false, true, Acquire, Relaxed) {
+                Ok(_) => Ok(TypeLockGuard { lock: self, zst: PhantomData }),
+                Err(_) => Err(TypeLockError::WouldBlock),
+            }
+        }
+    }
+
+    /// Returns `true` if a panic occured while this lock was held.  Always `false` without `feature = "std"`.
+    pub fn is_poisoned(&self) -> bool {
+        #[cfg(    feature = "std" )] { self.lock.is_poisoned() }
+        #[cfg(not(feature = "std"))] { false }
+    }
+
+    const STATIC_CHECK_Z_ZST : () = assert!(0 == size_of::<Z>(), "TypeLock<Z>: Z must be a ZST");
+}
+
+impl<'a, Z> TypeLockGuard<'a, Z> {
+    #[cfg(feature = "std")] fn new(guard: std::sync::MutexGuard<'a, ()>) -> Self { Self { _guard: guard, zst: PhantomData } }
+
+    /// Borrow the locked `Z` by value.
+    pub fn valrow(&self) -> Valrow<'_, Z> where Z: Borrowable { Valrow::new(self) }
+
+    /// Exclusively borrow the locked `Z` by value.
+    pub fn valrow_mut(&mut self) -> ValrowMut<'_, Z> where Z: BorrowableMut { ValrowMut::new(self) }
+}
+
+impl<Z> Deref for TypeLockGuard<'_, Z> {
+    type Target = Z;
+    fn deref(&self) -> &Z { unsafe { NonNull::<Z>::dangling().as_ref() } } // ZST owned by the TypeLock, which we've locked
+}
+
+impl<Z> DerefMut for TypeLockGuard<'_, Z> {
+    fn deref_mut(&mut self) -> &mut Z { unsafe { NonNull::<Z>::dangling().as_mut() } } // ZST owned by the TypeLock, which we've locked
+}
+
+#[cfg(not(feature = "std"))] impl<Z> Drop for TypeLockGuard<'_, Z> {
+    fn drop(&mut self) { self.lock.lock.store(false, core::sync::atomic::Ordering::Release) }
+}
+
+impl<G> TypeLockPoisoned<G> {
+    /// Ignore the poisoning, and access the guard anyways.
+    pub fn into_inner(self) -> G { self.0 }
+}
@@ -1,7 +1,8 @@
 use crate::*;
 
+use core::borrow::Borrow;
 use core::cmp::Ordering;
-use core::fmt::{self, Debug, Display, Formatter};
+use core::fmt::{self, Binary, Debug, Display, Formatter, LowerHex, Octal, Pointer, UpperHex};
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
 use core::mem::{align_of, size_of, transmute};
@@ -10,25 +11,49 @@ use core::ops::Deref;
 
 
 /// A by-value borrow.
-#[repr(transparent)] pub struct Valrow<'a, T: Borrowable>(T::Abi, PhantomData<&'a T>);
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout))]
+#[repr(transparent)] pub struct Valrow<'a, T: Borrowable + 'a>(T::Abi, PhantomData<&'a T>); // N.B. `'a` bound is implied, but zerocopy's derives need it spelled out
 
 unsafe impl<'a, T: Borrowable   > Send              for Valrow<'a, T> where &'a T : Send {}
 unsafe impl<'a, T: Borrowable   > Sync              for Valrow<'a, T> where &'a T : Sync {}
 impl<    T: Borrowable          > Copy              for Valrow<'_, T> {}
 impl<    T: Borrowable          > Clone             for Valrow<'_, T> { fn clone(&self)                        -> Self             { Self(self.0, self.1) }                            }
 impl<    T: Borrowable          > AsRef<T>          for Valrow<'_, T> { fn as_ref(&self)                       -> &T               { Self::as_ref(self) }                              }
+// N.B. `Borrow<T>` lets a `HashMap<Valrow<Arc<str>>, V>` be looked up by `&Arc<str>`.  The reverse would need `Arc<str>: Borrow<Valrow<Arc<str>>>`, which
+// the orphan rule forbids us from implementing: look a `HashMap<Arc<str>, V>` up by `&**valrow` (`&str`) instead.
+impl<    T: Borrowable          > Borrow<T>         for Valrow<'_, T> { fn borrow(&self)                       -> &T               { Self::as_ref(self) }                              }
 impl<    T: Borrowable          > Deref             for Valrow<'_, T> { fn deref(&self)                        -> &T               { Self::as_ref(self) } type Target = T;             }
 impl<'a, T: Borrowable          > From<&'a mut T>   for Valrow<'a, T> { fn from(reference: &'a mut T) -> Self { Self::new(reference) } }
 impl<'a, T: Borrowable          > From<&'a     T>   for Valrow<'a, T> { fn from(reference: &'a     T) -> Self { Self::new(reference) } }
+impl<'a, T: Borrowable + BorrowableMut> From<ValrowMut<'a, T>>     for Valrow<'a, T> { fn from(borrow: ValrowMut<'a, T>)     -> Self { ValrowMut::downgrade(borrow) } }
+impl<'a, T: Borrowable + BorrowableMut> From<&'a ValrowMut<'_, T>> for Valrow<'a, T> { fn from(borrow: &'a ValrowMut<'_, T>) -> Self { Self::new(borrow) } }
 
-// XXX: actually, how many of these traits are really needed?
+// Forward the traits `&T` would forward, so `Valrow<T>` is as usable as `&T`.
 impl<T: Borrowable + Debug      > Debug             for Valrow<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Debug       >::fmt(self, fmt) }             }
 impl<T: Borrowable + Display    > Display           for Valrow<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Display     >::fmt(self, fmt) }             }
+impl<T: Borrowable + Pointer    > Pointer           for Valrow<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Pointer     >::fmt(self, fmt) }             }
+impl<T: Borrowable + LowerHex   > LowerHex          for Valrow<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as LowerHex    >::fmt(self, fmt) }             }
+impl<T: Borrowable + UpperHex   > UpperHex          for Valrow<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as UpperHex    >::fmt(self, fmt) }             }
+impl<T: Borrowable + Octal      > Octal             for Valrow<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Octal       >::fmt(self, fmt) }             }
+impl<T: Borrowable + Binary     > Binary            for Valrow<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Binary      >::fmt(self, fmt) }             }
 impl<T: Borrowable + PartialEq  > PartialEq         for Valrow<'_, T> { fn eq(&self, other: &Self)             -> bool             { <T as PartialEq   >::eq(self, other) }            }
+impl<T: Borrowable + PartialEq  > PartialEq<T>      for Valrow<'_, T> { fn eq(&self, other: &T)                -> bool             { <T as PartialEq   >::eq(self, other) }            }
 impl<T: Borrowable + Eq         > Eq                for Valrow<'_, T> {}
 impl<T: Borrowable + PartialOrd > PartialOrd        for Valrow<'_, T> { fn partial_cmp(&self, other: &Self)    -> Option<Ordering> { <T as PartialOrd  >::partial_cmp(self, other) }   }
+impl<T: Borrowable + PartialOrd > PartialOrd<T>     for Valrow<'_, T> { fn partial_cmp(&self, other: &T)       -> Option<Ordering> { <T as PartialOrd  >::partial_cmp(self, other) }   }
 impl<T: Borrowable + Ord        > Ord               for Valrow<'_, T> { fn cmp(&self, other: &Self)            -> Ordering         { <T as Ord         >::cmp(self, other) }           }
 impl<T: Borrowable + Hash       > Hash              for Valrow<'_, T> { fn hash<H: Hasher>(&self, state: &mut H)                   { <T as Hash        >::hash(self, state) }          }
+#[cfg(feature = "serde")] impl<T: Borrowable + serde::Serialize> serde::Serialize for Valrow<'_, T> { fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { <T as serde::Serialize>::serialize(self, serializer) } }
+
+// N.B. `core::error::Error` postdates our MSRV (stabilized in 1.81), hence `std`
+#[cfg(feature = "std")] impl<T: Borrowable + std::error::Error> std::error::Error for Valrow<'_, T> { fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { <T as std::error::Error>::source(self) } }
+
+// A by-value shared borrow can't advance an `Iterator` (there's no `&mut T`), but `&Valrow<T>` can be iterated like `&T`.
+impl<'b, T: Borrowable> IntoIterator for &'b Valrow<'_, T> where &'b T : IntoIterator {
+    type Item       = <&'b T as IntoIterator>::Item;
+    type IntoIter   = <&'b T as IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter { Valrow::as_ref(self).into_iter() }
+}
 
 impl<'a, T: Borrowable> Valrow<'a, T> {
     /// Borrow `*reference` by value.
@@ -37,16 +62,82 @@ impl<'a, T: Borrowable> Valrow<'a, T> {
         Self(unsafe { *transmute::<&T, &T::Abi>(reference) }, PhantomData)
     }
 
+    /// Borrow every element of `slice` by value, without copying.
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// # use valrow::*;
+    /// # use std::sync::Arc;
+    /// let arcs = [Arc::new(1), Arc::new(2)];
+    /// let valrows : &[Valrow<Arc<i32>>] = Valrow::from_slice(&arcs);
+    /// let copies : Vec<Valrow<Arc<i32>>> = valrows.to_vec(); // copies `Valrow`s, doesn't touch refcounts
+    /// assert_eq!(Arc::strong_count(&copies[0]), 1);
+    /// # }
+    /// ```
+    #[inline(always)] pub fn from_slice(slice: &'a [T]) -> &'a [Self] {
+        let _ = Self::STATIC_CHECK_T_ABI;
+        unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) }
+    }
+
+    /// Borrow by value from a bitwise copy of a `T`.
+    ///
+    /// ### Safety
+    /// `abi` must be a bitwise copy of a `T` that remains borrowed (and thus valid, and unmodified) for `'a`.
+    #[inline(always)] pub(crate) const unsafe fn from_abi(abi: T::Abi) -> Self {
+        let _ = Self::STATIC_CHECK_T_ABI;
+        Self(abi, PhantomData)
+    }
+
+    /// The bitwise copy of `T` this borrow is made of.
+    #[inline(always)] pub(crate) const fn into_abi(this: Self) -> T::Abi { this.0 }
+
     fn as_ref(&self) -> &T {
         let _ = Self::STATIC_CHECK_T_ABI;
         unsafe { transmute(self) }
     }
 
-    /// Makes an effort to validate `T` and `T::Abi` are compatible.  This may have false negatives (e.g. fail to trip despite incompatability.)
-    const STATIC_CHECK_T_ABI : () = {
-        assert!(align_of::<       T >() == align_of::<T::Abi>());
-        assert!( size_of::<       T >() ==  size_of::<T::Abi>());
-        assert!(align_of::<Option<T>>() == align_of::<Option<T::Abi>>()); // imperfect check for niche compatability
-        assert!( size_of::<Option<T>>() ==  size_of::<Option<T::Abi>>()); // imperfect check for niche compatability
-    };
+    const STATIC_CHECK_T_ABI : () = static_check_abi::<T, T::Abi>();
+}
+
+/// Makes an effort to validate `T` and `Abi` are compatible.  This may have false negatives (e.g. fail to trip despite incompatability.)
+pub(crate) const fn static_check_abi<T, Abi>() {
+    assert!(align_of::<       T >() == align_of::<       Abi >());
+    assert!( size_of::<       T >() ==  size_of::<       Abi >());
+    assert!(align_of::<Option<T>>() == align_of::<Option<Abi>>()); // imperfect check for niche compatability
+    assert!( size_of::<Option<T>>() ==  size_of::<Option<Abi>>()); // imperfect check for niche compatability
+}
+
+impl<'a, T: BorrowableStr> Valrow<'a, T> {
+    /// Access the borrowed string for the entire lifetime `'a` (rather than the lifetime of `this`.)
+    pub fn as_str(this: Self) -> &'a str { unsafe { &*(<T as Deref>::deref(Self::as_ref(&this)) as *const str) } }
+}
+
+
+
+/// Forwarded traits make [`Valrow`]s usable as map keys, in comparisons, iteration, and formatting, much like `&T`.
+/// Run under Miri: see [Testing](crate#testing) (filter: `valrow_forwards`.)
+#[cfg(feature = "std")] #[test] fn valrow_forwards_like_ref() {
+    use alloc::sync::Arc;
+    use std::collections::HashMap;
+
+    let key : Arc<str> = "key".into();
+    let mut map = HashMap::new();
+    map.insert(Valrow::new(&key), 42); // HashMap<Valrow<Arc<str>>, i32>
+    assert_eq!(map.get(&key), Some(&42)); // looked up by &Arc<str>
+    assert!(map.contains_key(&Arc::<str>::from("key")));
+
+    let mut owned : HashMap<Arc<str>, i32> = HashMap::new();
+    owned.insert(key.clone(), 42);
+    assert_eq!(owned.get(&**Valrow::new(&key)), Some(&42)); // looked up by &str
+    assert!(Valrow::new(&key) == key);
+
+    #[derive(Clone, Copy)] struct Ids([i32; 3]);
+    unsafe impl Borrowable for Ids { type Abi = Self; }
+    impl<'a> IntoIterator for &'a Ids { type Item = &'a i32; type IntoIter = core::slice::Iter<'a, i32>; fn into_iter(self) -> Self::IntoIter { self.0.iter() } }
+    let ids = Ids([1, 2, 3]);
+    let ids = Valrow::new(&ids);
+    let mut sum = 0;
+    for id in &ids { sum += id; } // `&Valrow<Ids>: IntoIterator`
+    assert_eq!(sum, 6);
+    assert_eq!(alloc::format!("{:x} {:b}", Valrow::new(&255_u8), Valrow::new(&5_u8)), "ff 101");
 }
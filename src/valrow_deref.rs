@@ -0,0 +1,95 @@
+use crate::*;
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::mem::transmute;
+use core::ops::Deref;
+
+
+
+/// A by-value borrow of a smart pointer `P`, which [`Deref`]s straight to <code>P::[Target](Deref::Target)</code>.
+///
+/// <code>[Valrow]\<[Arc](alloc::sync::Arc)\<T\>\></code> derefs to <code>[Arc](alloc::sync::Arc)\<T\></code>, requiring awkward `**borrow`s to reach `T`,
+/// and failing to satisfy generic code that wants <code>impl [Deref]\<Target = T\></code>.
+/// <code>[ValrowDeref]\<[Arc](alloc::sync::Arc)\<T\>\></code> has the same layout, but derefs to `T`.
+/// [`Borrow`], [`AsRef`], and the comparison/formatting traits likewise forward to `T`.
+/// The owner itself remains available via <code>[ValrowDeref::owner]\(&self\) -> &P</code>.
+///
+/// With `feature = "stable_deref_trait"`, this implements `StableDeref` and `CloneStableDeref` when `P: StableDeref`.
+///
+/// ### Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use valrow::*;
+/// use std::sync::Arc;
+///
+/// fn generic(value: impl core::ops::Deref<Target = str>) -> usize { value.len() }
+///
+/// let a : Arc<str> = "example".into();
+/// let b = ValrowDeref::new(&a);
+/// let c = b; // Copy
+/// assert_eq!(generic(b), 7);
+/// assert_eq!(c.to_uppercase(), "EXAMPLE");
+/// assert_eq!(Arc::strong_count(ValrowDeref::owner(&c)), 1);
+///
+/// # #[cfg(feature = "stable_deref_trait")] {
+/// fn stable(_: impl stable_deref_trait::CloneStableDeref<Target = str>) {}
+/// stable(b);
+/// # }
+/// # }
+/// ```
+///
+/// The second type parameter, `T`, is always <code>P::[Target](Deref::Target)</code>.
+/// It only exists to allow implementing <code>[Borrow]\<T\></code> without conflicting with core's <code>impl [Borrow]\<T\> for T</code>.
+#[repr(transparent)] pub struct ValrowDeref<'a, P: Borrowable + Deref<Target = T>, T: ?Sized = <P as Deref>::Target>(P::Abi, PhantomData<&'a P>);
+
+unsafe impl<'a, P: Borrowable + Deref<Target = T>, T: ?Sized  > Send                      for ValrowDeref<'a, P, T> where &'a P : Send {}
+unsafe impl<'a, P: Borrowable + Deref<Target = T>, T: ?Sized  > Sync                      for ValrowDeref<'a, P, T> where &'a P : Sync {}
+impl<    P: Borrowable + Deref<Target = T>, T: ?Sized         > Copy                      for ValrowDeref<'_, P, T> {}
+impl<    P: Borrowable + Deref<Target = T>, T: ?Sized         > Clone                     for ValrowDeref<'_, P, T> { fn clone(&self)  -> Self { *self } }
+impl<    P: Borrowable + Deref<Target = T>, T: ?Sized         > AsRef<T>                  for ValrowDeref<'_, P, T> { fn as_ref(&self) -> &T   { self } }
+impl<    P: Borrowable + Deref<Target = T>, T: ?Sized         > Borrow<T>                 for ValrowDeref<'_, P, T> { fn borrow(&self) -> &T   { self } }
+impl<    P: Borrowable + Deref<Target = T>, T: ?Sized         > Deref                     for ValrowDeref<'_, P, T> { fn deref(&self)  -> &T   { <P as Deref>::deref(Self::owner(self)) } type Target = T; }
+impl<'a, P: Borrowable + Deref<Target = T>, T: ?Sized         > From<&'a P>               for ValrowDeref<'a, P, T> { fn from(reference: &'a P)               -> Self { Self::new(reference) } }
+impl<'a, P: Borrowable + Deref<Target = T>, T: ?Sized         > From<Valrow<'a, P>>       for ValrowDeref<'a, P, T> { fn from(borrow: Valrow<'a, P>)          -> Self { Self::from_valrow(borrow) } }
+impl<'a, P: Borrowable + Deref<Target = T>, T: ?Sized         > From<ValrowDeref<'a, P, T>> for Valrow<'a, P>     { fn from(borrow: ValrowDeref<'a, P, T>)  -> Self { ValrowDeref::valrow(borrow) } }
+
+impl<P: Borrowable + Deref<Target = T>, T: ?Sized + Debug     > Debug                     for ValrowDeref<'_, P, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Debug       >::fmt(self, fmt) }             }
+impl<P: Borrowable + Deref<Target = T>, T: ?Sized + Display   > Display                   for ValrowDeref<'_, P, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Display     >::fmt(self, fmt) }             }
+impl<P: Borrowable + Deref<Target = T>, T: ?Sized + PartialEq > PartialEq                 for ValrowDeref<'_, P, T> { fn eq(&self, other: &Self)             -> bool             { <T as PartialEq   >::eq(self, other) }            }
+impl<P: Borrowable + Deref<Target = T>, T: ?Sized + Eq        > Eq                        for ValrowDeref<'_, P, T> {}
+impl<P: Borrowable + Deref<Target = T>, T: ?Sized + PartialOrd> PartialOrd                for ValrowDeref<'_, P, T> { fn partial_cmp(&self, other: &Self)    -> Option<Ordering> { <T as PartialOrd  >::partial_cmp(self, other) }   }
+impl<P: Borrowable + Deref<Target = T>, T: ?Sized + Ord       > Ord                       for ValrowDeref<'_, P, T> { fn cmp(&self, other: &Self)            -> Ordering         { <T as Ord         >::cmp(self, other) }           }
+impl<P: Borrowable + Deref<Target = T>, T: ?Sized + Hash      > Hash                      for ValrowDeref<'_, P, T> { fn hash<H: Hasher>(&self, state: &mut H)                   { <T as Hash        >::hash(self, state) }          }
+#[cfg(feature = "serde")] impl<P: Borrowable + Deref<Target = T>, T: ?Sized + serde::Serialize> serde::Serialize for ValrowDeref<'_, P, T> { fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { <T as serde::Serialize>::serialize(self, serializer) } }
+
+#[cfg(feature = "stable_deref_trait")] unsafe impl<P: Borrowable + stable_deref_trait::StableDeref<Target = T>, T: ?Sized> stable_deref_trait::StableDeref      for ValrowDeref<'_, P, T> {} // copies share P's stable pointee
+#[cfg(feature = "stable_deref_trait")] unsafe impl<P: Borrowable + stable_deref_trait::StableDeref<Target = T>, T: ?Sized> stable_deref_trait::CloneStableDeref for ValrowDeref<'_, P, T> {} // clones are bitwise copies of P
+
+impl<'a, P: Borrowable + Deref<Target = T>, T: ?Sized> ValrowDeref<'a, P, T> {
+    /// Borrow `*reference` by value.
+    #[inline(always)] pub fn new(reference: &'a P) -> Self {
+        let _ = Self::STATIC_CHECK_P_ABI;
+        Self(unsafe { *transmute::<&P, &P::Abi>(reference) }, PhantomData)
+    }
+
+    /// Access the borrowed owner.
+    pub fn owner(this: &Self) -> &P {
+        let _ = Self::STATIC_CHECK_P_ABI;
+        unsafe { transmute(this) }
+    }
+
+    /// Convert into a [`Valrow`], which [`Deref`]s to `P` instead of `T`.
+    pub fn valrow(this: Self) -> Valrow<'a, P> {
+        unsafe { Valrow::from_abi(this.0) } // same `P::Abi`, same `'a`
+    }
+
+    fn from_valrow(borrow: Valrow<'a, P>) -> Self {
+        Self(Valrow::into_abi(borrow), PhantomData) // same `P::Abi`, same `'a`
+    }
+
+    const STATIC_CHECK_P_ABI : () = static_check_abi::<P, P::Abi>();
+}
@@ -2,17 +2,20 @@ use crate::*;
 
 #[cfg(all(doc, feature = "alloc"))] use alloc::boxed::Box;
 #[cfg(all(doc, feature = "alloc"))] use alloc::vec::Vec;
+#[cfg(doc)] use core::cell::Cell;
 
+use core::borrow::{Borrow, BorrowMut};
 use core::cmp::Ordering;
-use core::fmt::{self, Debug, Display, Formatter};
+use core::fmt::{self, Binary, Debug, Display, Formatter, LowerHex, Octal, Pointer, UpperHex};
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
-use core::mem::{align_of, size_of, transmute};
+use core::mem::transmute;
 use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
 
 
 
-/// A by-value mutable/exclusive borrow.  Only usable for Zero Sized Types.  Only useful if <code>\![Copy]</code>.
+/// A by-value mutable/exclusive borrow.  Requires <code>T: [BorrowableMut]</code>.
 ///
 /// A "by-value exclusive borrow" effectively has:
 /// *   *shared* (by-value) access to *direct* data (e.g. the ptr/length/capacity/allocator of a [`Vec`])
@@ -20,7 +23,9 @@ use core::ops::{Deref, DerefMut};
 ///
 /// No simple reference type can represent this mixed-exclusivity model.
 ///
-/// [`ValrowMut`] only manages to support ZSTs because exclusive access to nothing is equivalent to shared access to nothing.
+/// [`ValrowMut`] only provides [`DerefMut`] when <code>T: [BorrowableMut]\<AbiMut = ()\></code> (i.e. `T` is a ZST), since `&mut T` access to a copy would allow moving the copy out:
+/// replacing or resizing an owner itself, or smuggling out a `&mut U` aliasing the original.
+/// Exclusive access to indirect data is available through [`IndirectMut`] instead.
 ///
 ///
 ///
@@ -28,9 +33,11 @@ use core::ops::{Deref, DerefMut};
 /// *   Own a (frozen) instance         &mdash; e.g. `move`, [`Copy`], [`Clone`], fetch atomic loads, etc.
 /// *   Discard access to direct data   &mdash; e.g. reborrow <code>[Box]\<T\></code> → `&mut T`, discarding access to ownership and allocator)
 /// *   Discard mutable access          &mdash; e.g. reborrow <code>[Arc]\<T\></code> → <code>[Valrow]\<[Arc]\<T\>\></code>
+/// *   Discard mutable access to direct data &mdash; e.g. <code>[IndirectMut]\<P\></code>, granting `&mut P::Target` but only `&P`, for <code>P: [BorrowableIndirectMut]</code>
 /// *   Write your own custom type.
 ///     While macros could *theoretically* help with trivial examples of this, in practice it seems anything useful would need to be largely hand-written.
-///     The following example will use <code>[ABox]</code> as if we were writing `ialloc`:
+///     The following example will use <code>[ABox]</code> as if we were writing `ialloc`.
+///     (With `feature = "ialloc"`, valrow ships this as <code>[ABoxMut]\<'b, T, A\></code>, an [`IndirectMut`].)
 ///
 /// ```rust
 /// use ialloc::boxed::ABox;
@@ -42,7 +49,7 @@ use core::ops::{Deref, DerefMut};
 /// use core::mem::ManuallyDrop;
 /// use core::ops::{Deref, DerefMut};
 ///
-/// # #[cfg(nope)]
+/// # #[cfg(nope)] // valrow provides this with feature = "ialloc"
 /// unsafe impl<T, A: Free> valrow::Borrowable for ABox<T, A> {}
 ///
 /// #[repr(transparent)] pub struct ABoxMut<'b, T, A: Free>(
@@ -55,7 +62,7 @@ use core::ops::{Deref, DerefMut};
 ///         Self(ManuallyDrop::new(unsafe { core::ptr::read(reference) }), PhantomData)
 ///     }
 ///
-///     # #[cfg(nope)] // can't impl Borrowable for foreign crate
+///     # #[cfg(feature = "ialloc")] // valrow implements Borrowable for ABox with feature = "ialloc"
 ///     pub fn borrow(this: &    Self) -> Valrow<ABox<T, A>> { Valrow::new(&this.0) }
 ///     pub fn as_ref(this: &    Self) -> &    T { &    *this.0 }
 ///     pub fn as_mut(this: &mut Self) -> &mut T { &mut *this.0 } // sound
@@ -70,9 +77,9 @@ use core::ops::{Deref, DerefMut};
 /// ```
 ///
 ///
-/// ### What would actually explode with `ValrowMut` on !ZSTs?
+/// ### What would actually explode with `DerefMut` on owners?
 ///
-/// Pretty much everything.
+/// Pretty much everything.  The ZST check rejects all of these at compile time:
 ///
 /// ```rust
 /// # #[cfg(nope)] {
@@ -96,6 +103,12 @@ use core::ops::{Deref, DerefMut};
 /// drop(borrow);
 /// assert!(value.len() == 3);  // we have access to 3 dropped elements
 ///
+/// # }
+/// ```
+///
+/// Direct interior mutability, while not unsound, also explodes: ([`Cell`] doesn't implement [`BorrowableMut`] for this reason.)
+/// ```rust
+/// # #[cfg(nope)] {
 /// let mut value = Cell::new(1);
 /// let mut borrow = ValrowMut::new(&mut value);
 /// borrow.set(2);
@@ -107,30 +120,75 @@ use core::ops::{Deref, DerefMut};
 ///
 /// [ABox]: https://docs.rs/ialloc/0.0.0-2023-05-28/ialloc/boxed/struct.ABox.html
 ///
-#[repr(transparent)] pub struct ValrowMut<'a, T: Borrowable>(T::Abi, PhantomData<&'a mut T>);
-
-unsafe impl<'a, T: Borrowable   > Send              for ValrowMut<'a, T> where &'a mut T : Send {}
-unsafe impl<'a, T: Borrowable   > Sync              for ValrowMut<'a, T> where &'a mut T : Sync {}
-impl<    T: Borrowable          > AsRef<T>          for ValrowMut<'_, T> { fn as_ref(&    self)                   -> &    T           { Self::as_ref(self) }                              }
-impl<    T: Borrowable          > AsMut<T>          for ValrowMut<'_, T> { fn as_mut(&mut self)                   -> &mut T           { Self::as_mut(self) }                              }
-impl<    T: Borrowable          > Deref             for ValrowMut<'_, T> { fn deref    (&    self)                -> &    T           { Self::as_ref(self) } type Target = T;             }
-impl<    T: Borrowable          > DerefMut          for ValrowMut<'_, T> { fn deref_mut(&mut self)                -> &mut T           { Self::as_mut(self) }                              }
-impl<'a, T: Borrowable          > From<&'a mut T>   for ValrowMut<'a, T> { fn from(reference: &'a mut T) -> Self { Self::new(reference) } }
-
-// XXX: actually, how many of these traits are really needed?
-impl<T: Borrowable + Debug      > Debug             for ValrowMut<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Debug       >::fmt(self, fmt) }             }
-impl<T: Borrowable + Display    > Display           for ValrowMut<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Display     >::fmt(self, fmt) }             }
-impl<T: Borrowable + PartialEq  > PartialEq         for ValrowMut<'_, T> { fn eq(&self, other: &Self)             -> bool             { <T as PartialEq   >::eq(self, other) }            }
-impl<T: Borrowable + Eq         > Eq                for ValrowMut<'_, T> {}
-impl<T: Borrowable + PartialOrd > PartialOrd        for ValrowMut<'_, T> { fn partial_cmp(&self, other: &Self)    -> Option<Ordering> { <T as PartialOrd  >::partial_cmp(self, other) }   }
-impl<T: Borrowable + Ord        > Ord               for ValrowMut<'_, T> { fn cmp(&self, other: &Self)            -> Ordering         { <T as Ord         >::cmp(self, other) }           }
-impl<T: Borrowable + Hash       > Hash              for ValrowMut<'_, T> { fn hash<H: Hasher>(&self, state: &mut H)                   { <T as Hash        >::hash(self, state) }          }
-
-impl<'a, T: Borrowable> ValrowMut<'a, T> {
+#[repr(transparent)] pub struct ValrowMut<'a, T: BorrowableMut>(T::AbiMut, PhantomData<&'a mut T>);
+
+unsafe impl<'a, T: BorrowableMut  > Send            for ValrowMut<'a, T> where &'a mut T : Send {}
+unsafe impl<'a, T: BorrowableMut  > Sync            for ValrowMut<'a, T> where &'a mut T : Sync {}
+impl<    T: BorrowableMut         > AsRef<T>        for ValrowMut<'_, T> { fn as_ref(&    self)                   -> &    T           { Self::as_ref(self) }                              }
+impl<    T: BorrowableMut<AbiMut = ()>> AsMut<T>        for ValrowMut<'_, T> { fn as_mut(&mut self)                   -> &mut T           { Self::as_mut(self) }                              }
+impl<    T: BorrowableMut         > Borrow<T>       for ValrowMut<'_, T> { fn borrow(&    self)                   -> &    T           { Self::as_ref(self) }                              }
+impl<    T: BorrowableMut<AbiMut = ()>> BorrowMut<T>    for ValrowMut<'_, T> { fn borrow_mut(&mut self)               -> &mut T           { Self::as_mut(self) }                              }
+impl<    T: BorrowableMut         > Deref           for ValrowMut<'_, T> { fn deref    (&    self)                -> &    T           { Self::as_ref(self) } type Target = T;             }
+impl<    T: BorrowableMut<AbiMut = ()>> DerefMut        for ValrowMut<'_, T> { fn deref_mut(&mut self)                -> &mut T           { Self::as_mut(self) }                              }
+impl<'a, T: BorrowableMut         > From<&'a mut T> for ValrowMut<'a, T> { fn from(reference: &'a mut T) -> Self { Self::new(reference) } }
+impl<'a, T: BorrowableMut         > From<&'a mut ValrowMut<'_, T>> for ValrowMut<'a, T> { fn from(borrow: &'a mut ValrowMut<'_, T>) -> Self { ValrowMut::reborrow(borrow) } }
+
+// Forward the traits `&mut T` would forward, so `ValrowMut<T>` is as usable as `&mut T`.
+impl<T: BorrowableMut + Debug     > Debug           for ValrowMut<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Debug       >::fmt(self, fmt) }             }
+impl<T: BorrowableMut + Display   > Display         for ValrowMut<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Display     >::fmt(self, fmt) }             }
+impl<T: BorrowableMut + Pointer   > Pointer         for ValrowMut<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Pointer     >::fmt(self, fmt) }             }
+impl<T: BorrowableMut + LowerHex  > LowerHex        for ValrowMut<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as LowerHex    >::fmt(self, fmt) }             }
+impl<T: BorrowableMut + UpperHex  > UpperHex        for ValrowMut<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as UpperHex    >::fmt(self, fmt) }             }
+impl<T: BorrowableMut + Octal     > Octal           for ValrowMut<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Octal       >::fmt(self, fmt) }             }
+impl<T: BorrowableMut + Binary    > Binary          for ValrowMut<'_, T> { fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result      { <T as Binary      >::fmt(self, fmt) }             }
+impl<T: BorrowableMut + PartialEq > PartialEq       for ValrowMut<'_, T> { fn eq(&self, other: &Self)             -> bool             { <T as PartialEq   >::eq(self, other) }            }
+impl<T: BorrowableMut + PartialEq > PartialEq<T>    for ValrowMut<'_, T> { fn eq(&self, other: &T)                -> bool             { <T as PartialEq   >::eq(self, other) }            }
+impl<T: BorrowableMut + Eq        > Eq              for ValrowMut<'_, T> {}
+impl<T: BorrowableMut + PartialOrd> PartialOrd      for ValrowMut<'_, T> { fn partial_cmp(&self, other: &Self)    -> Option<Ordering> { <T as PartialOrd  >::partial_cmp(self, other) }   }
+impl<T: BorrowableMut + PartialOrd> PartialOrd<T>   for ValrowMut<'_, T> { fn partial_cmp(&self, other: &T)       -> Option<Ordering> { <T as PartialOrd  >::partial_cmp(self, other) }   }
+impl<T: BorrowableMut + Ord       > Ord             for ValrowMut<'_, T> { fn cmp(&self, other: &Self)            -> Ordering         { <T as Ord         >::cmp(self, other) }           }
+impl<T: BorrowableMut + Hash      > Hash            for ValrowMut<'_, T> { fn hash<H: Hasher>(&self, state: &mut H)                   { <T as Hash        >::hash(self, state) }          }
+#[cfg(feature = "serde")] impl<T: BorrowableMut + serde::Serialize> serde::Serialize for ValrowMut<'_, T> { fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { <T as serde::Serialize>::serialize(self, serializer) } }
+
+#[cfg(feature = "std")] impl<T: BorrowableMut + std::error::Error> std::error::Error for ValrowMut<'_, T> { fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { <T as std::error::Error>::source(self) } }
+
+// `&mut ValrowMut<T>` is already `IntoIterator` via `Iterator`, which conflicts with forwarding `&mut T: IntoIterator`.
+impl<T: BorrowableMut<AbiMut = ()> + Iterator> Iterator for ValrowMut<'_, T> {
+    type Item = T::Item;
+    fn next(&mut self) -> Option<T::Item> { Self::as_mut(self).next() }
+    fn size_hint(&self) -> (usize, Option<usize>) { Self::as_ref(self).size_hint() }
+}
+
+impl<'b, T: BorrowableMut> IntoIterator for &'b ValrowMut<'_, T> where &'b T : IntoIterator {
+    type Item       = <&'b T as IntoIterator>::Item;
+    type IntoIter   = <&'b T as IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter { ValrowMut::as_ref(self).into_iter() }
+}
+
+impl<'a, T: BorrowableMut> ValrowMut<'a, T> {
     /// Borrow `*reference` by value.
     #[inline(always)] pub fn new(reference: &'a mut T) -> Self {
         let _ = Self::STATIC_CHECK_T_ABI;
-        Self(unsafe { *transmute::<&mut T, &mut T::Abi>(reference) }, PhantomData)
+        Self(unsafe { *transmute::<&mut T, &mut T::AbiMut>(reference) }, PhantomData)
+    }
+
+    /// Temporarily lend out `this` (e.g. to a ZST callback), without giving up on `this` for the rest of `'a`.
+    #[inline(always)] pub fn reborrow(this: &mut Self) -> ValrowMut<'_, T> { ValrowMut(this.0, PhantomData) }
+
+    /// Give up exclusive access for the rest of `'a`, in exchange for a shared by-value borrow.
+    ///
+    /// ```
+    /// # use valrow::*;
+    /// let mut unit = ();
+    /// let exclusive = ValrowMut::new(&mut unit);
+    /// let shared : Valrow<()> = ValrowMut::downgrade(exclusive);
+    /// let copy = shared; // `Valrow`s are `Copy`
+    /// # let _ = (shared, copy);
+    /// ```
+    #[inline(always)] pub fn downgrade(this: Self) -> Valrow<'a, T> where T : Borrowable {
+        // `Valrow::new(Self::as_ref(&this))` would only last as long as `&this`, but `this` (and with it, exclusive access) is given up for all of `'a`.
+        let abi = Valrow::into_abi(Valrow::new(Self::as_ref(&this)));
+        unsafe { Valrow::from_abi(abi) }
     }
 
     fn as_ref(&self) -> &T {
@@ -138,15 +196,22 @@ impl<'a, T: Borrowable> ValrowMut<'a, T> {
         unsafe { transmute(self) }
     }
 
+    const STATIC_CHECK_T_ABI : () = static_check_abi::<T, T::AbiMut>();
+}
+
+// `AbiMut = ()` (and thus, per the ABI check, a ZST `T`) is required for `&mut T` access: see the `ValrowMut` docs.
+impl<'a, T: BorrowableMut<AbiMut = ()>> ValrowMut<'a, T> {
+    /// Convert back into a plain exclusive reference.  Requires <code>T: [BorrowableMut]\<AbiMut = ()\></code> (i.e. a ZST.)
+    ///
+    /// A ZST has no direct data to decohere, so any well-aligned address will do as "the" address of `T`.
+    #[inline(always)] pub fn into_mut(this: Self) -> &'a mut T {
+        let _ = Self::STATIC_CHECK_T_ABI;
+        let _ = this;
+        unsafe { NonNull::<T>::dangling().as_mut() }
+    }
+
     fn as_mut(&mut self) -> &mut T {
         let _ = Self::STATIC_CHECK_T_ABI;
         unsafe { transmute(self) }
     }
-
-    /// Makes an effort to validate `T` and `T::Abi` are compatible.  This should have no false negatives?
-    const STATIC_CHECK_T_ABI : () = {
-        assert!(0               ==  size_of::<T     >()); // T      *must* be a ZST
-        assert!(0               ==  size_of::<T::Abi>()); // T::Abi *must* be a ZST
-        assert!(align_of::<T>() == align_of::<T::Abi>());
-    };
 }
@@ -0,0 +1,112 @@
+use core::mem::size_of;
+use core::ptr::{self, NonNull};
+use core::task::{RawWaker, RawWakerVTable, Waker};
+
+
+
+/// Create a [`Waker`] that calls the ZST `wake` when woken, without allocating.
+///
+/// Since `F` is a [`Copy`] ZST, every `F` is interchangeable: the [`Waker`]'s data pointer is unused, and its `clone`/`drop` do nothing.
+/// This gives `no_std` executors wakers without `alloc`, `Arc`, or `static` state per waker, while still dispatching to arbitrary code
+/// (typically a non-capturing closure, or one capturing only ZSTs like <code>[Valrow](crate::Valrow)\<Zst\></code>s, as in the [ZST Callbacks](crate::Examples::ZstCallbacks) example.)
+/// Fails to compile if `F` isn't a ZST.
+///
+/// ### Examples
+/// ```
+/// use valrow::*;
+/// use core::future::Future;
+/// use core::pin::pin;
+/// use core::sync::atomic::{AtomicBool, Ordering::*};
+/// use core::task::{Context, Poll};
+///
+/// static WOKEN : AtomicBool = AtomicBool::new(true);
+///
+/// fn block_on<F: Future>(future: F) -> F::Output {
+///     let waker = zst_waker(|| WOKEN.store(true, Release));
+///     let mut cx = Context::from_waker(&waker);
+///     let mut future = pin!(future);
+///     loop {
+///         if WOKEN.swap(false, Acquire) {
+///             if let Poll::Ready(output) = future.as_mut().poll(&mut cx) { return output }
+///         } else {
+///             core::hint::spin_loop(); // or wait for an interrupt
+///         }
+///     }
+/// }
+///
+/// struct YieldOnce(bool);
+/// impl Future for YieldOnce {
+///     type Output = &'static str;
+///     fn poll(mut self: core::pin::Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+///         if self.0 { return Poll::Ready("done") }
+///         self.0 = true;
+///         let waker = ShallowCopy::new(cx.waker()); // pass the waker along without `clone`/`drop` vtable calls
+///         waker.wake_by_ref();
+///         Poll::Pending
+///     }
+/// }
+///
+/// assert_eq!(block_on(YieldOnce(false)), "done");
+/// ```
+pub fn zst_waker<F: Fn() + Copy + Send + Sync + 'static>(wake: F) -> Waker {
+    unsafe { Waker::from_raw(RawWaker::new(ptr::null(), zst_waker_vtable(wake))) }
+}
+
+/// Get a `'static` [`RawWakerVTable`] whose `wake`/`wake_by_ref` call the ZST `F`, and whose `clone`/`drop` do nothing.  See [`zst_waker`].
+///
+/// The vtable ignores the data pointer, so any data pointer (e.g. null) may be paired with it.
+/// `wake` is only taken to name the type of closures: being a [`Copy`] ZST, it's conjured again as needed.
+pub const fn zst_waker_vtable<F: Fn() + Copy + Send + Sync + 'static>(wake: F) -> &'static RawWakerVTable {
+    let _ = ZstWaker::<F>::IS_ZST;
+    let _ = wake;
+    &ZstWaker::<F>::VTABLE
+}
+
+struct ZstWaker<F>(F);
+impl<F: Fn() + Copy + Send + Sync + 'static> ZstWaker<F> {
+    const IS_ZST : () = assert!(0 == size_of::<F>(), "zst_waker: `F` must be a ZST (capture nothing, or only ZSTs)");
+    const VTABLE : RawWakerVTable = RawWakerVTable::new(Self::clone, Self::wake, Self::wake, Self::drop);
+
+    unsafe fn clone(data: *const ()) -> RawWaker { RawWaker::new(data, &Self::VTABLE) }
+    unsafe fn wake(_data: *const ()) {
+        let _ = Self::IS_ZST;
+        let wake = unsafe { NonNull::<F>::dangling().as_ptr().read() }; // `F` is a `Copy` ZST: conjuring another is harmless
+        wake();
+    }
+    unsafe fn drop(_data: *const ()) {}
+}
+
+
+
+/// Copying `Waker`s via [`ShallowCopy`](crate::ShallowCopy) must not call the vtable's `clone` nor `drop`.
+#[test] fn shallow_copy_wakers() {
+    use crate::ShallowCopy;
+    use core::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+    static CLONES : AtomicUsize = AtomicUsize::new(0);
+    static DROPS  : AtomicUsize = AtomicUsize::new(0);
+    static WAKES  : AtomicUsize = AtomicUsize::new(0);
+    static VTABLE : RawWakerVTable = RawWakerVTable::new(
+        |data| { CLONES.fetch_add(1, Relaxed); RawWaker::new(data, &VTABLE) },
+        |_data| { WAKES.fetch_add(1, Relaxed); DROPS.fetch_add(1, Relaxed); },
+        |_data| { WAKES.fetch_add(1, Relaxed); },
+        |_data| { DROPS.fetch_add(1, Relaxed); },
+    );
+
+    let waker = unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) };
+    let copies = [(); 4].map(|_| ShallowCopy::new(&waker));
+    let copies = copies.clone();
+    for copy in copies.iter() { copy.wake_by_ref() }
+    assert!(copies[0].will_wake(&waker));
+    assert_eq!((CLONES.load(Relaxed), DROPS.load(Relaxed), WAKES.load(Relaxed)), (0, 0, 4));
+
+    drop(waker.clone());
+    drop(waker);
+    assert_eq!((CLONES.load(Relaxed), DROPS.load(Relaxed)), (1, 2));
+
+    let zst = zst_waker(|| { WAKES.fetch_add(1, Relaxed); });
+    let cloned = zst.clone();
+    cloned.wake();
+    zst.wake_by_ref();
+    assert_eq!(WAKES.load(Relaxed), 6);
+}
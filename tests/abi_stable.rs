@@ -0,0 +1,18 @@
+//! Build `tests/abi_stable_plugin`, then have its loader load its cdylib plugin and pass <code>Valrow\<RArc\<_\>\></code>s across the plugin boundary.
+#![cfg(all(feature = "abi_stable", not(miri)))] // see `common`
+
+mod common;
+
+use std::process::Command;
+
+#[test] fn abi_stable_plugin() {
+    let (mut cargo, target_dir) = common::cargo("abi_stable_plugin", &["build", "--workspace"]);
+    assert!(cargo.status().unwrap().success(), "failed to build tests/abi_stable_plugin");
+
+    let debug = target_dir.join("debug");
+    let loader = debug.join(format!("valrow_abi_stable_loader{}", std::env::consts::EXE_SUFFIX));
+    let plugin = debug.join(format!("{}valrow_abi_stable_plugin{}", std::env::consts::DLL_PREFIX, std::env::consts::DLL_SUFFIX));
+    let output = Command::new(&loader).arg(&plugin).output().unwrap();
+    assert!(output.status.success(), "{} failed:\n{}", loader.display(), String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "ok");
+}
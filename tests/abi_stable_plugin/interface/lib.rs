@@ -0,0 +1,24 @@
+//! Shared between the plugin (`../plugin`) and its loader (`loader.rs`): abi_stable verifies both agree on these layouts at load time.
+//! N.B. both must use *this* package: layouts are tagged with the name of the package that defined them.
+
+use abi_stable::StableAbi;
+use abi_stable::library::RootModule;
+use abi_stable::sabi_types::VersionStrings;
+use abi_stable::std_types::{RArc, RVec};
+use valrow::Valrow;
+
+#[repr(C)] #[derive(StableAbi)]
+#[sabi(kind(Prefix(prefix_ref = PluginRef)))]
+#[sabi(missing_field(panic))]
+pub struct Plugin {
+    pub sum             : extern "C" fn(values: Valrow<RArc<RVec<i32>>>) -> i32,
+    #[sabi(last_prefix_field)]
+    pub strong_count    : extern "C" fn(values: Valrow<RArc<RVec<i32>>>) -> usize,
+}
+
+impl RootModule for PluginRef {
+    abi_stable::declare_root_module_statics!{PluginRef}
+    const BASE_NAME         : &'static str      = "valrow_abi_stable_plugin";
+    const NAME              : &'static str      = "valrow_abi_stable_plugin";
+    const VERSION_STRINGS   : VersionStrings    = VersionStrings::new("0.0.0"); // not `package_version_strings!()`: the loader is a different package
+}
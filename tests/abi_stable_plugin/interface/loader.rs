@@ -0,0 +1,19 @@
+//! Usage: `valrow_abi_stable_loader path/to/plugin.dll`
+
+use valrow_abi_stable_interface::*;
+
+use abi_stable::library::RootModule;
+use abi_stable::std_types::{RArc, RVec};
+use valrow::Valrow;
+
+fn main() {
+    let dll = std::env::args_os().nth(1).expect("usage: valrow_abi_stable_loader path/to/plugin.dll");
+    let dll = std::path::Path::new(&dll);
+    let plugin = PluginRef::load_from_file(dll).unwrap_or_else(|err| panic!("failed to load {}: {err}", dll.display()));
+
+    let values = RArc::new(RVec::from(vec![1, 2, 3]));
+    assert_eq!(plugin.sum()(Valrow::new(&values)), 6);
+    assert_eq!(plugin.strong_count()(Valrow::new(&values)), 1); // passing a `Valrow` didn't clone the `RArc`
+    assert_eq!(RArc::strong_count(&values), 1);
+    println!("ok");
+}
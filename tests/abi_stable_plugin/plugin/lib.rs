@@ -0,0 +1,12 @@
+use valrow_abi_stable_interface::*;
+
+use abi_stable::prefix_type::PrefixTypeTrait;
+use abi_stable::std_types::{RArc, RVec};
+use valrow::Valrow;
+
+#[abi_stable::export_root_module] pub fn get_plugin() -> PluginRef {
+    Plugin { sum, strong_count }.leak_into_prefix()
+}
+
+extern "C" fn sum(values: Valrow<RArc<RVec<i32>>>) -> i32 { values.iter().sum() }
+extern "C" fn strong_count(values: Valrow<RArc<RVec<i32>>>) -> usize { RArc::strong_count(&values) }
@@ -0,0 +1,57 @@
+//! Hammer an `ArcSwap` with writers while readers pass arrays of <code>Valrow::from_guard</code>s around, checking for torn reads and refcount leaks.
+#![cfg(all(feature = "arc-swap", feature = "alloc"))]
+
+use arc_swap::ArcSwap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering::*};
+use std::thread;
+use valrow::*;
+
+const WRITES    : usize = if cfg!(miri) { 20 } else { 20_000 };
+const READERS   : usize = 4;
+const GUARDS    : usize = 4;
+
+/// Every element of a generation is the generation number: any mismatch would be a torn or freed read.
+type Generation = Arc<[usize; 8]>;
+
+#[inline(never)] fn check(values: &[Valrow<Generation>]) -> usize {
+    let mut max = 0;
+    for value in values {
+        let generation = value[0];
+        assert!(value.iter().all(|&v| v == generation), "torn read: {:?}", &value[..]);
+        max = max.max(generation);
+    }
+    max
+}
+
+#[test] fn arc_swap_from_guard_stress() {
+    let swap = ArcSwap::new(Arc::new([0; 8]));
+    let done = AtomicBool::new(false);
+
+    thread::scope(|scope| {
+        for _ in 0 .. READERS {
+            scope.spawn(|| {
+                let mut last = 0;
+                while !done.load(Acquire) {
+                    let guards = [(); GUARDS].map(|_| swap.load());
+                    let values = guards.iter().map(Valrow::from_guard).collect::<Vec<_>>();
+                    let copies = values.clone(); // copies `Valrow`s, doesn't touch refcounts
+                    assert_eq!(values[0][0], copies[0][0]);
+
+                    let max = check(&copies);
+                    assert!(values[0][0] >= last, "generations went backwards: {} after {last}", values[0][0]);
+                    last = max;
+                }
+            });
+        }
+
+        scope.spawn(|| {
+            for generation in 1 ..= WRITES { swap.store(Arc::new([generation; 8])) }
+            done.store(true, Release);
+        });
+    });
+
+    let last = swap.load_full();
+    assert_eq!(last[0], WRITES);
+    assert_eq!(Arc::strong_count(&last), 2, "`swap` + `last`: `Valrow`s shouldn't leak (or steal) references");
+}
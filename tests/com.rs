@@ -0,0 +1,116 @@
+//! Implement a mock `IUnknown`-derived shader resource view, then pass arrays of <code>Valrow\<ComPtr\<_\>\></code>s to a fake `PSSetShaderResources`, checking refcounts never change.
+#![allow(non_snake_case)] // COM method names
+
+use core::ffi::c_void;
+use std::sync::atomic::{AtomicU32, Ordering::*};
+use std::ptr::NonNull;
+use valrow::com::*;
+use valrow::*;
+
+const IID_IUNKNOWN : Guid = Guid { data1: 0x00000000, data2: 0x0000, data3: 0x0000, data4: [0xC0, 0, 0, 0, 0, 0, 0, 0x46] };
+const E_NOINTERFACE : i32 = 0x80004002_u32 as i32;
+
+#[repr(C)] struct IShaderResourceViewVtbl {
+    base:   IUnknownVtbl,
+    GetId:  unsafe extern "system" fn(this: *mut ShaderResourceView) -> u32,
+}
+
+/// A mock `ID3D11ShaderResourceView`: a vtable pointer, followed by an `AtomicU32` refcount (which tolerates `AddRef`/`Release` while `&ShaderResourceView`s exist.)
+#[repr(C)] struct ShaderResourceView {
+    vtbl:   &'static IShaderResourceViewVtbl,
+    refs:   AtomicU32,
+    id:     u32,
+}
+
+unsafe impl Interface for ShaderResourceView {}
+
+static SRV_VTBL : IShaderResourceViewVtbl = IShaderResourceViewVtbl {
+    base: IUnknownVtbl { query_interface: srv_query_interface, add_ref: srv_add_ref, release: srv_release },
+    GetId: srv_get_id,
+};
+
+unsafe extern "system" fn srv_query_interface(this: *mut c_void, iid: *const Guid, object: *mut *mut c_void) -> i32 {
+    if *iid != IID_IUNKNOWN { *object = core::ptr::null_mut(); return E_NOINTERFACE }
+    srv_add_ref(this);
+    *object = this;
+    0
+}
+unsafe extern "system" fn srv_add_ref(this: *mut c_void) -> u32 { 1 + (*this.cast::<ShaderResourceView>()).refs.fetch_add(1, Relaxed) }
+unsafe extern "system" fn srv_release(this: *mut c_void) -> u32 {
+    let remaining = (*this.cast::<ShaderResourceView>()).refs.fetch_sub(1, Release) - 1;
+    if remaining == 0 {
+        std::sync::atomic::fence(Acquire);
+        drop(Box::from_raw(this.cast::<ShaderResourceView>()));
+    }
+    remaining
+}
+unsafe extern "system" fn srv_get_id(this: *mut ShaderResourceView) -> u32 { (*this).id }
+
+impl ShaderResourceView {
+    fn new(id: u32) -> ComPtr<Self> {
+        let raw = Box::into_raw(Box::new(Self { vtbl: &SRV_VTBL, refs: AtomicU32::new(1), id }));
+        unsafe { ComPtr::from_raw(NonNull::new_unchecked(raw)) }
+    }
+
+    fn refs(&self) -> u32 { self.refs.load(Acquire) }
+    fn GetId(&self) -> u32 { unsafe { (self.vtbl.GetId)(self as *const Self as *mut Self) } }
+}
+
+/// A fake `ID3D11DeviceContext`, recording the ids of bound views.
+#[derive(Default)] struct DeviceContext { ps_ids: [Option<u32>; 8] }
+
+impl DeviceContext {
+    /// The "C ABI" half: `void PSSetShaderResources(UINT StartSlot, UINT NumViews, ID3D11ShaderResourceView* const* ppShaderResourceViews)`
+    unsafe extern "system" fn raw_ps_set_shader_resources(this: *mut Self, start_slot: u32, num_views: u32, views: *const *mut ShaderResourceView) {
+        for i in 0 .. num_views as usize {
+            let view = *views.add(i);
+            (*this).ps_ids[start_slot as usize + i] = if view.is_null() { None } else { Some(((*view).vtbl.GetId)(view)) };
+        }
+    }
+
+    /// The "Rust" half: takes borrowed views, rather than forcing callers to clone owned ones.
+    fn PSSetShaderResources(&mut self, start_slot: u32, views: &[Option<Valrow<ComPtr<ShaderResourceView>>>]) {
+        let raw = Valrow::as_raw_option_slice(views);
+        assert!(start_slot as usize + raw.len() <= self.ps_ids.len());
+        unsafe { Self::raw_ps_set_shader_resources(self, start_slot, raw.len() as u32, raw.as_ptr()) }
+    }
+}
+
+#[test] fn ps_set_shader_resources() {
+    let views = [1, 2, 3].map(ShaderResourceView::new);
+    let mut context = DeviceContext::default();
+
+    let borrowed : &[Valrow<ComPtr<ShaderResourceView>>] = Valrow::from_slice(&views);
+    let bind = [Some(borrowed[2]), None, Some(borrowed[0]), Some(borrowed[0])]; // copies `Valrow`s, doesn't touch refcounts
+    assert!(views.iter().all(|v| v.refs() == 1));
+
+    context.PSSetShaderResources(1, &bind);
+    assert_eq!(context.ps_ids, [None, Some(3), None, Some(1), Some(1), None, None, None]);
+    assert!(views.iter().all(|v| v.refs() == 1));
+
+    let raw = Valrow::as_raw_slice(borrowed);
+    assert_eq!(raw.iter().map(|&v| unsafe { (*v).id }).collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(Valrow::as_raw(borrowed[1]), ComPtr::as_raw(&views[1]));
+    assert_eq!(bind[0].unwrap().GetId(), 3); // `Valrow` → `ComPtr` → `ShaderResourceView`
+    assert!(views.iter().all(|v| v.refs() == 1));
+}
+
+#[test] fn clone_drop_query_interface() {
+    let view = ShaderResourceView::new(42);
+    let clone = view.clone();
+    assert_eq!(view.refs(), 2);
+    assert_eq!(clone, view);
+
+    let mut unknown = core::ptr::null_mut();
+    let vtbl = &view.vtbl.base;
+    assert_eq!(unsafe { (vtbl.query_interface)(ComPtr::as_raw(&view).as_ptr().cast(), &IID_IUNKNOWN, &mut unknown) }, 0);
+    let unknown = unsafe { ComPtr::<ShaderResourceView>::from_raw(NonNull::new(unknown.cast()).unwrap()) };
+    assert_eq!(view.refs(), 3);
+
+    drop(unknown);
+    drop(clone);
+    assert_eq!(view.refs(), 1);
+    let raw = ComPtr::into_raw(view);
+    assert_eq!(unsafe { raw.as_ref() }.refs(), 1);
+    drop(unsafe { ComPtr::from_raw(raw) }); // frees (leaks would be caught by Miri)
+}
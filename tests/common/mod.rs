@@ -0,0 +1,18 @@
+//! Shared harness for tests that build (and run) the nested cargo projects under `tests/`.
+//! Miri can't spawn processes, so users gate themselves on `not(miri)`.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// `cargo {args} --manifest-path tests/{project}/Cargo.toml --target-dir target/{project}`, and said target dir.
+///
+/// Uses the same `cargo` and `target` directory as the test itself, so nested projects are rebuilt with the same toolchain and kept out of the source tree.
+pub fn cargo(project: &str, args: &[&str]) -> (Command, PathBuf) {
+    let exe = std::env::current_exe().unwrap(); // target/{profile}/deps/{test}-{hash}
+    let target_dir = exe.ancestors().nth(3).unwrap().join(project);
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join(project).join("Cargo.toml");
+
+    let mut cmd = Command::new(env!("CARGO"));
+    cmd.args(args).arg("--manifest-path").arg(manifest).arg("--target-dir").arg(&target_dir);
+    (cmd, target_dir)
+}
@@ -0,0 +1,11 @@
+//! Build and run `tests/cxx_bridge`, whose C++ side receives <code>Valrow\<SharedPtr\<_\>\></code>s and <code>Valrow\<UniquePtr\<_\>\></code>s as `const&`s.
+#![cfg(all(feature = "cxx", not(miri)))] // see `common`
+
+mod common;
+
+#[test] fn cxx_bridge() {
+    let (mut cargo, _) = common::cargo("cxx_bridge", &["run", "--quiet"]);
+    let output = cargo.output().unwrap();
+    assert!(output.status.success(), "tests/cxx_bridge failed:\n{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "ok");
+}
@@ -0,0 +1,12 @@
+#include "valrow_cxx_bridge/bridge.h"
+
+namespace valrow_cxx {
+    std::shared_ptr<Widget> new_shared_widget(int32_t value) { return std::make_shared<Widget>(Widget { value }); }
+    std::unique_ptr<Widget> new_unique_widget(int32_t value) { return std::unique_ptr<Widget>(new Widget { value }); }
+
+    size_t  shared_use_count        (const std::shared_ptr<Widget>& widget) { return size_t(widget.use_count()); }
+    size_t  shared_copy_use_count   (const std::shared_ptr<Widget>& widget) { std::shared_ptr<Widget> copy = widget; return size_t(copy.use_count()); }
+    int32_t shared_value            (const std::shared_ptr<Widget>& widget) { return widget->value; }
+    int32_t unique_value            (const std::unique_ptr<Widget>& widget) { return widget->value; }
+    const Widget* unique_get        (const std::unique_ptr<Widget>& widget) { return widget.get(); }
+}
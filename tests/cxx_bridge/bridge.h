@@ -0,0 +1,18 @@
+#pragma once
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+
+namespace valrow_cxx {
+    struct Widget { int32_t value; };
+
+    std::shared_ptr<Widget> new_shared_widget(int32_t value);
+    std::unique_ptr<Widget> new_unique_widget(int32_t value);
+
+    // `const&`s: these may be handed bitwise copies from `Valrow`s, which C++ must not take ownership of
+    size_t  shared_use_count        (const std::shared_ptr<Widget>& widget);
+    size_t  shared_copy_use_count   (const std::shared_ptr<Widget>& widget);
+    int32_t shared_value            (const std::shared_ptr<Widget>& widget);
+    int32_t unique_value            (const std::unique_ptr<Widget>& widget);
+    const Widget* unique_get        (const std::unique_ptr<Widget>& widget);
+}
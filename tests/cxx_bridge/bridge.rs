@@ -0,0 +1,46 @@
+//! Pass <code>Valrow\<SharedPtr\<_\>\></code>s and <code>Valrow\<UniquePtr\<_\>\></code>s to C++ as `const&`s, and check use counts don't change.
+
+use cxx::UniquePtr;
+use valrow::Valrow;
+
+#[cxx::bridge(namespace = "valrow_cxx")]
+mod ffi {
+    unsafe extern "C++" {
+        include!("valrow_cxx_bridge/bridge.h");
+
+        type Widget;
+        fn new_shared_widget(value: i32) -> SharedPtr<Widget>;
+        fn new_unique_widget(value: i32) -> UniquePtr<Widget>;
+
+        fn shared_use_count     (widget: &SharedPtr<Widget>) -> usize;
+        fn shared_copy_use_count(widget: &SharedPtr<Widget>) -> usize;
+        fn shared_value         (widget: &SharedPtr<Widget>) -> i32;
+        fn unique_value         (widget: &UniquePtr<Widget>) -> i32;
+        fn unique_get           (widget: &UniquePtr<Widget>) -> *const Widget;
+    }
+}
+
+fn main() {
+    let shared = ffi::new_shared_widget(42);
+    assert_eq!(ffi::shared_use_count(&shared), 1);
+
+    let valrows = [Valrow::new(&shared); 4]; // `Copy`: no `SharedPtr::clone`
+    for valrow in valrows {
+        assert_eq!(ffi::shared_value(&valrow), 42);
+        assert_eq!(ffi::shared_use_count(&valrow), 1);
+    }
+    assert_eq!(ffi::shared_copy_use_count(&valrows[0]), 2); // C++ copying from a `Valrow` is a real `std::shared_ptr` copy...
+    assert_eq!(ffi::shared_use_count(&shared), 1);          // ...which was released again
+    let cloned = shared.clone();
+    assert_eq!(ffi::shared_use_count(&valrows[1]), 2);      // `Valrow`s observe the shared control block
+    drop(cloned);
+
+    let unique = ffi::new_unique_widget(7);
+    let valrow = Valrow::new(&unique);
+    let copy = valrow;
+    assert_eq!(ffi::unique_value(&copy), 7);
+    assert_eq!(ffi::unique_get(&copy), ffi::unique_get(&unique));
+    assert!(std::ptr::eq(UniquePtr::as_ref(&copy).unwrap(), unique.as_ref().unwrap()));
+
+    println!("ok");
+}
@@ -0,0 +1,9 @@
+fn main() {
+    cxx_build::bridge("bridge.rs")
+        .file("bridge.cc")
+        .flag_if_supported("-std=c++14")
+        .compile("valrow_cxx_bridge");
+    println!("cargo:rerun-if-changed=bridge.rs");
+    println!("cargo:rerun-if-changed=bridge.cc");
+    println!("cargo:rerun-if-changed=bridge.h");
+}
@@ -0,0 +1,46 @@
+//! Run `examples/debugger.rs` under gdb in batch mode, and check `debug/valrow_gdb.py` displays [`Valrow`]s and [`ValrowMut`]s as their borrowed `T`.
+//! Requires `gdb`, so ignored by default: run with `cargo test --test gdb -- --ignored`.
+
+#[cfg(all(feature = "alloc", not(miri)))] #[test] #[ignore = "requires gdb"] fn gdb_pretty_printers() {
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+
+    assert!(Command::new("gdb").arg("--version").output().is_ok(), "gdb not found (install it, or don't run ignored tests)");
+
+    let build = Command::new(env!("CARGO"))
+        .args(["build", "--example", "debugger", "--features", "alloc", "--message-format=json-render-diagnostics"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output().unwrap();
+    assert!(build.status.success(), "cargo build --example debugger failed:\n{}", String::from_utf8_lossy(&build.stderr));
+    let debugger = String::from_utf8_lossy(&build.stdout).lines()
+        .filter(|l| l.contains(r#""reason":"compiler-artifact""#) && l.contains(r#""name":"debugger""#))
+        .find_map(|l| Some(l.split(r#""executable":""#).nth(1)?.split('"').next()?.replace(r"\\", r"\")))
+        .map(PathBuf::from)
+        .expect("cargo build --example debugger didn't report an executable");
+
+    let source = std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/debugger.rs")).unwrap();
+    let line = 1 + source.lines().position(|l| l.trim() == "dbg!(d);").expect("examples/debugger.rs: expected `dbg!(d);`");
+
+    let output = Command::new("gdb")
+        .args(["-batch", "-nx"])
+        .args(["-iex", &format!("add-auto-load-safe-path {}", debugger.display())])
+        .args(["-ex", &format!("break debugger.rs:{line}")])
+        .args(["-ex", "run"])
+        .args(["-ex", "print a"])   // $1: Arc<i32>
+        .args(["-ex", "print b"])   // $2: Valrow<Arc<i32>>
+        .args(["-ex", "print *e"])  // $3: ValrowMut<()>
+        .args(["-ex", "print *d"])  // $4: ()
+        .arg(&debugger)
+        .output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let printed = |n: usize| -> &str {
+        let prefix = format!("${n} = ");
+        stdout.lines().find_map(|l| l.strip_prefix(prefix.as_str())).unwrap_or_else(|| panic!("gdb didn't print ${n}:\n{stdout}\n{}", String::from_utf8_lossy(&output.stderr)))
+    };
+
+    assert!(!printed(2).contains("__0"),    "Valrow<Arc<i32>> wasn't pretty printed:\n{stdout}");
+    assert_eq!(printed(2), printed(1),      "Valrow<Arc<i32>> should print as Arc<i32>:\n{stdout}");
+    assert!(!printed(3).contains("__0"),    "ValrowMut<()> wasn't pretty printed:\n{stdout}");
+    assert_eq!(printed(3), printed(4),      "ValrowMut<()> should print as ():\n{stdout}");
+}
@@ -0,0 +1,36 @@
+//! Embed a Python interpreter, then pass <code>Valrow\<Py\<_\>\></code>s around without refcount traffic, converting them to `Borrowed` and `Bound` as needed.
+//! `prepare_freethreaded_python` does what pyo3's `auto-initialize` feature would, without forcing that feature (incompatible with `extension-module`) onto dependents.
+#![cfg(all(feature = "pyo3", target_os = "linux", not(miri)))]
+
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PyString};
+use valrow::*;
+
+#[test] fn pyo3_valrow_py() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let list : Py<PyList> = PyList::new(py, [1, 2, 3]).unwrap().unbind();
+        let refcnt = list.get_refcnt(py);
+
+        let a = Valrow::new(&list);
+        let b = a; // Copy: no GIL needed, no incref
+        let copies = [a, b, a, b];
+        assert_eq!(list.get_refcnt(py), refcnt);
+
+        let borrowed : Borrowed<PyList> = Valrow::bind_borrowed(copies[3], py);
+        assert_eq!(borrowed.len(), 3);
+        assert_eq!(b.bind(py).get_item(1).unwrap().extract::<i32>().unwrap(), 2);
+        assert_eq!(list.get_refcnt(py), refcnt);
+
+        let owned : Bound<PyList> = borrowed.to_owned(); // explicitly incref'd
+        assert_eq!(list.get_refcnt(py), refcnt + 1);
+        owned.append(4).unwrap();
+        drop(owned);
+        assert_eq!(list.get_refcnt(py), refcnt);
+        assert_eq!(a.bind(py).len(), 4);
+
+        #[inline(never)] fn greet(name: Valrow<Py<PyString>>, py: Python) -> String { format!("hello {}", *Valrow::bind_borrowed(name, py)) }
+        let name = PyString::new(py, "world").unbind();
+        assert_eq!(greet(Valrow::new(&name), py), "hello world");
+    });
+}
@@ -0,0 +1,66 @@
+//! Generate C vtables from ZST trait impls with `#[valrow::vtable]`, then call every kind of trampoline through them, as C would.
+#![cfg(feature = "macros")]
+
+use std::ffi::{c_char, CStr};
+use std::sync::Mutex;
+use valrow::*;
+
+#[repr(C)] struct DeviceVtbl {
+    name:       unsafe extern "system" fn() -> *const c_char,
+    open:       unsafe extern "system" fn(port: u16) -> bool,
+    is_open:    unsafe extern "system" fn() -> bool,
+    write:      unsafe extern "system" fn(byte: u8) -> u32,
+    written:    unsafe extern "system" fn() -> u32,
+    close:      unsafe extern "system" fn() -> Option<Port>,
+    first:      for<'b> unsafe extern "system" fn(a: Option<&'b u8>, b: Option<&u8>) -> Option<&'b u8>,
+}
+
+type Port = core::num::NonZeroU16;
+
+trait Device : Borrowable + BorrowableMut + Sized {
+    type Stats;
+    fn name() -> *const c_char;
+    fn open(&mut self, port: u16) -> bool;
+    fn is_open(&self) -> bool;
+    fn write(this: ValrowMut<Self>, byte: u8) -> Self::Stats;
+    fn written(this: Valrow<Self>) -> Self::Stats;
+    fn close(this: ValrowMut<'_, Self>) -> Option<Port>;
+    fn first<'b>(a: Option<&'b u8>, b: Option<&u8>) -> Option<&'b u8>;
+}
+
+/// Single-instance ZST representing the port state in `SERIAL` (never handed out to Rust code, only conjured by the vtable's trampolines.)
+struct Serial(());
+unsafe impl Borrowable    for Serial { type Abi     = (); }
+unsafe impl BorrowableMut for Serial { type AbiMut  = (); }
+
+static SERIAL : Mutex<(Option<Port>, u32)> = Mutex::new((None, 0));
+
+#[valrow::vtable(static SERIAL_VTBL: DeviceVtbl, extern "system")]
+impl Device for Serial {
+    type Stats = u32;
+    fn name() -> *const c_char { b"serial\0".as_ptr().cast() }
+    fn open(&mut self, port: u16) -> bool { let mut serial = SERIAL.lock().unwrap(); serial.0 = Port::new(port); serial.0.is_some() }
+    fn is_open(&self) -> bool { SERIAL.lock().unwrap().0.is_some() }
+    fn write(_this: ValrowMut<Self>, _byte: u8) -> Self::Stats { let mut serial = SERIAL.lock().unwrap(); serial.1 += 1; serial.1 }
+    fn written(_this: Valrow<Self>) -> Self::Stats { SERIAL.lock().unwrap().1 }
+    fn close(_this: ValrowMut<'_, Self>) -> Option<Port> { SERIAL.lock().unwrap().0.take() }
+    fn first<'b>(a: Option<&'b u8>, _b: Option<&u8>) -> Option<&'b u8> { a }
+}
+
+#[test] fn serial_vtable() {
+    let vtbl = &SERIAL_VTBL;
+    unsafe {
+        assert_eq!(CStr::from_ptr((vtbl.name)()).to_str(), Ok("serial"));
+        assert!(!(vtbl.is_open)());
+        assert!(!(vtbl.open)(0));
+        assert!((vtbl.open)(8080));
+        assert!((vtbl.is_open)());
+        assert_eq!((vtbl.write)(b'a'), 1);
+        assert_eq!((vtbl.write)(b'b'), 2);
+        assert_eq!((vtbl.written)(), 2);
+        assert_eq!((vtbl.close)(), Port::new(8080));
+        assert_eq!((vtbl.close)(), None);
+        assert_eq!((vtbl.first)(Some(&42), None), Some(&42));
+        assert_eq!((vtbl.first)(None, Some(&42)), None);
+    }
+}
@@ -0,0 +1,221 @@
+//! Proc macros for [valrow](https://docs.rs/valrow).  Use via valrow's `macros` feature (e.g. `#[valrow::vtable(...)]`) rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::visit_mut::VisitMut;
+use syn::*;
+
+
+
+/// <code>#\[vtable(pub static NAME: Vtbl)\] impl Trait for Zst { ... }</code> &mdash; emits a `static` C vtable of trampolines into a ZST's trait impl.
+///
+/// Each method of the impl becomes an `unsafe extern "C" fn` trampoline (or `extern "system"` etc. with <code>#\[vtable(static NAME: Vtbl, extern "system")\]</code>),
+/// assigned to the field of `Vtbl` with the same name.  `Vtbl` is typically a `#[repr(C)]` struct mirroring a C header, with `unsafe extern "C" fn(...)` fields.
+/// Since `Zst` is a ZST, the trampolines need no context parameter: they conjure the `Zst` out of thin air (with a compile time check that it's a ZST), then forward to the method.
+/// The first parameter of each method decides how:
+///
+/// | First parameter                                   | Trampoline passes                     |
+/// | ------------------------------------------------- | ------------------------------------- |
+/// | `&self`                                           | a conjured `&Zst`                     |
+/// | `&mut self`                                       | a conjured `&mut Zst`                 |
+/// | <code>this: [Valrow]\<Self\></code>               | a conjured `Valrow<Zst>`              |
+/// | <code>this: [ValrowMut]\<Self\></code>            | a conjured `ValrowMut<Zst>`           |
+/// | anything else (or nothing)                        | no receiver: all parameters forwarded |
+///
+/// Remaining parameters and return types are passed through as-is, so they should be FFI safe.
+/// By-value `self` is rejected (conjuring an owned, possibly non-[`Copy`], `Zst` would duplicate it), as are type and const generics.
+///
+/// ### Safety
+/// The trampolines are `unsafe`: whoever calls them must uphold what holding a `&Zst` (or `&mut Zst`, `ValrowMut<Zst>`) would,
+/// e.g. not calling `&mut self` entries reentrantly or from multiple threads at once, if `Zst` is meant to guard exclusive access to C state.
+///
+/// ### Examples
+/// ```
+/// use valrow::*;
+/// use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
+///
+/// #[repr(C)] pub struct PluginVtbl {
+///     pub version:    unsafe extern "C" fn() -> u32,
+///     pub name:       unsafe extern "C" fn() -> *const u8,
+///     pub add:        unsafe extern "C" fn(value: u32) -> u32,
+///     pub total:      unsafe extern "C" fn() -> u32,
+/// }
+///
+/// pub trait Plugin : Borrowable + BorrowableMut + Sized {
+///     fn version() -> u32;
+///     fn name(&self) -> *const u8;
+///     fn add(this: ValrowMut<Self>, value: u32) -> u32;
+///     fn total(this: Valrow<Self>) -> u32;
+/// }
+///
+/// pub struct Counter(()); // single-instance ZST, guarding `TOTAL`
+/// unsafe impl Borrowable    for Counter { type Abi    = (); }
+/// unsafe impl BorrowableMut for Counter { type AbiMut = (); }
+/// static TOTAL : AtomicU32 = AtomicU32::new(0);
+///
+/// #[valrow::vtable(pub static COUNTER_VTBL: PluginVtbl)]
+/// impl Plugin for Counter {
+///     fn version() -> u32 { 1 }
+///     fn name(&self) -> *const u8 { b"counter\0".as_ptr() }
+///     fn add(_this: ValrowMut<Self>, value: u32) -> u32 { TOTAL.fetch_add(value, Relaxed) + value }
+///     fn total(_this: Valrow<Self>) -> u32 { TOTAL.load(Relaxed) }
+/// }
+///
+/// // e.g. from C
+/// unsafe {
+///     assert_eq!((COUNTER_VTBL.version)(), 1);
+///     assert_eq!(*(COUNTER_VTBL.name)(), b'c');
+///     assert_eq!((COUNTER_VTBL.add)(2), 2);
+///     assert_eq!((COUNTER_VTBL.add)(3), 5);
+///     assert_eq!((COUNTER_VTBL.total)(), 5);
+/// }
+/// ```
+///
+/// [Valrow]:       https://docs.rs/valrow/latest/valrow/struct.Valrow.html
+/// [ValrowMut]:    https://docs.rs/valrow/latest/valrow/struct.ValrowMut.html
+#[proc_macro_attribute] pub fn vtable(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as VtableArgs);
+    let item = parse_macro_input!(item as ItemImpl);
+    match expand_vtable(args, item) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// `[pub] static NAME : Vtbl [, extern "abi"]`
+struct VtableArgs {
+    vis:    Visibility,
+    name:   Ident,
+    ty:     Type,
+    abi:    Option<Abi>,
+}
+
+impl Parse for VtableArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let vis = input.parse()?;
+        let _ : Token![static] = input.parse()?;
+        let name = input.parse()?;
+        let _ : Token![:] = input.parse()?;
+        let ty = input.parse()?;
+        let abi = if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() { Some(input.parse()?) } else { None };
+        Ok(Self { vis, name, ty, abi })
+    }
+}
+
+fn expand_vtable(args: VtableArgs, item: ItemImpl) -> Result<TokenStream2> {
+    let VtableArgs { vis, name, ty, abi } = args;
+    let abi = abi.map_or_else(|| quote!{ extern "C" }, |abi| abi.into_token_stream());
+    let Some((None, trait_, _)) = &item.trait_ else { return Err(Error::new(item.impl_token.span, "#[valrow::vtable] expects `impl Trait for Zst`")) };
+    if !item.generics.params.is_empty() { return Err(Error::new(item.generics.span(), "#[valrow::vtable] doesn't support generic impls (each instantiation would need its own `static`)")) }
+    let self_ty = &*item.self_ty;
+    let prelude = quote!{ ::valrow::_valrow_macros_prelude };
+
+    let mut trampolines = Vec::new();
+    let mut fields = Vec::new();
+    for impl_item in item.items.iter() {
+        let ImplItem::Fn(method) = impl_item else { continue };
+        let sig = &method.sig;
+        if let Some(asyncness) = &sig.asyncness { return Err(Error::new(asyncness.span, "#[valrow::vtable] can't make C trampolines for `async fn`s")) }
+        if let Some(variadic) = &sig.variadic   { return Err(Error::new(variadic.span(), "#[valrow::vtable] can't make C trampolines for variadic fns")) }
+        if let Some(param) = sig.generics.params.iter().find(|p| !matches!(p, GenericParam::Lifetime(_))) {
+            return Err(Error::new(param.span(), "#[valrow::vtable] only supports lifetime generics (C can't call generic fns)"))
+        }
+
+        let mut inputs = sig.inputs.iter().peekable();
+        let conjure = match inputs.peek() {
+            Some(FnArg::Receiver(receiver)) => {
+                let mutable = match &*receiver.ty {
+                    Type::Reference(r) if is_self(&r.elem, self_ty) => r.mutability.is_some(),
+                    _ => return Err(Error::new(receiver.span(), "#[valrow::vtable] only supports `&self` and `&mut self` receivers (conjuring an owned `self` would duplicate it)")),
+                };
+                let _ = inputs.next();
+                Some(if mutable { quote!{ unsafe { #prelude::zst_mut::<#self_ty>() } } } else { quote!{ unsafe { #prelude::zst_ref::<#self_ty>() } } })
+            },
+            Some(FnArg::Typed(typed)) => match valrow_of_self(&typed.ty, self_ty) {
+                Some(false) => { let _ = inputs.next(); Some(quote!{ ::valrow::Valrow::new(unsafe { #prelude::zst_ref::<#self_ty>() }) }) },
+                Some(true)  => { let _ = inputs.next(); Some(quote!{ ::valrow::ValrowMut::new(unsafe { #prelude::zst_mut::<#self_ty>() }) }) },
+                None        => None,
+            },
+            None => None,
+        };
+
+        let mut params = Vec::new();
+        let mut forward = Vec::new();
+        forward.extend(conjure);
+        for (i, input) in inputs.enumerate() {
+            let FnArg::Typed(typed) = input else { unreachable!("receivers are always first") };
+            let arg = Ident::new(&format!("arg{i}"), Span::mixed_site());
+            let mut ty = (*typed.ty).clone();
+            ReplaceSelf { self_ty, trait_ }.visit_type_mut(&mut ty);
+            params.push(quote!{ #arg: #ty });
+            forward.push(arg.into_token_stream());
+        }
+
+        let mut output = sig.output.clone();
+        ReplaceSelf { self_ty, trait_ }.visit_return_type_mut(&mut output);
+        let generics = &sig.generics;
+        let ident = &sig.ident;
+        trampolines.push(quote!{
+            #[allow(non_snake_case)] unsafe #abi fn #ident #generics (#(#params),*) #output { <#self_ty as #trait_>::#ident(#(#forward),*) }
+        });
+        fields.push(quote!{ #ident: #ident });
+    }
+
+    Ok(quote!{
+        #item
+
+        #vis static #name : #ty = {
+            #(#trampolines)*
+            type Vtbl = #ty;
+            Vtbl { #(#fields),* }
+        };
+    })
+}
+
+/// `Self`, or the impl's self type itself
+fn is_self(ty: &Type, self_ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) if path.is_ident("Self") => true,
+        Type::Paren(paren) => is_self(&paren.elem, self_ty),
+        Type::Group(group) => is_self(&group.elem, self_ty),
+        ty => ty.to_token_stream().to_string() == self_ty.to_token_stream().to_string(),
+    }
+}
+
+/// `Some(false)` for <code>Valrow\<Self\></code>, `Some(true)` for <code>ValrowMut\<Self\></code>, `None` otherwise
+fn valrow_of_self(ty: &Type, self_ty: &Type) -> Option<bool> {
+    let Type::Path(TypePath { qself: None, path }) = ty else { return None };
+    let last = path.segments.last()?;
+    let mutable = match last.ident.to_string().as_str() { "Valrow" => false, "ValrowMut" => true, _ => return None };
+    let PathArguments::AngleBracketed(args) = &last.arguments else { return None };
+    let mut types = args.args.iter().filter_map(|arg| match arg { GenericArgument::Type(ty) => Some(ty), _ => None });
+    match (types.next(), types.next()) {
+        (Some(ty), None) if is_self(ty, self_ty) => Some(mutable),
+        _ => None,
+    }
+}
+
+/// Rewrites `Self` → `Zst` and `Self::Assoc` → `<Zst as Trait>::Assoc`, since trampolines aren't within the impl.
+struct ReplaceSelf<'a> {
+    self_ty:    &'a Type,
+    trait_:     &'a Path,
+}
+
+impl VisitMut for ReplaceSelf<'_> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(TypePath { qself: None, path }) = ty {
+            if path.is_ident("Self") {
+                *ty = self.self_ty.clone();
+                return;
+            } else if path.segments.first().is_some_and(|s| s.ident == "Self") {
+                let (self_ty, trait_) = (self.self_ty, self.trait_);
+                let rest = path.segments.iter().skip(1);
+                *ty = parse_quote!{ <#self_ty as #trait_>::#(#rest)::* };
+            }
+        }
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+}